@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 use anchor_spl::token_2022::Token2022;
-use anchor_spl::token_interface::{self as token, Mint, MintTo, TokenAccount, TransferChecked};
+use anchor_spl::token_interface::{
+    self as token, token_metadata_initialize, Mint, MintTo, SetAuthority, TokenAccount,
+    TokenMetadataInitialize, TransferChecked,
+};
 
 declare_id!("AvFrAyZRux3AUC3U1p82QuGzysqq85jdFfVzMB8rkqSy");
 
@@ -12,23 +16,56 @@ declare_id!("AvFrAyZRux3AUC3U1p82QuGzysqq85jdFfVzMB8rkqSy");
 //
 // 1. MINT TOKEN (0.01 SOL):
 //    - Creates SPL Token-2022 with 1B supply (9 decimals)
+//    - Name / symbol / URI stored on the mint (metadata extension)
 //    - 100% (1B tokens) → Protocol vault
+//    - Mint authority revoked right after, no freeze authority
 //    - Minter gets 0% (fair launch)
 //
 // 2. INITIALIZE POOL (AUTOMATIC):
-//    - FIXED: 0.02 SOL + 800M tokens
-//    - User only needs to call initialize (no parameters)
-//    - Uses VIRTUAL RESERVES for pricing (pump.fun style)
+//    - Parameters from an admin LaunchConfig preset (default: 0.02 SOL +
+//      800M tokens), snapshotted into the pool
+//    - Optional dev buy in the same instruction
+//    - Launch window: per-wallet buy cap for the first slots
+//    - Price curve chosen per pool: constant-product on VIRTUAL RESERVES
+//      (pump.fun style), linear or exponential
+//
+//    (launch_meme does 1 + 2 + an optional creator buy in one transaction)
 //
 // 3. BUY/SELL:
 //    - Users trade directly with pool (no LP tokens)
-//    - 0.3% fee on each trade
+//    - Exact-input (swap_*) or exact-output (buy_exact_tokens / sell_for_exact_sol)
+//    - quote_buy / quote_sell return the same pricing as return data
+//    - Trading fee (default 0.3%, set per LaunchConfig), always in SOL → protocol fee vault
+//    - Creator fee (default 0.1%) → per-token vault, claimable by the minter
+//
+// 4. GRADUATION:
+//    - Curve completes when tokens sell out or real SOL hits the threshold
+//    - Completed curves reject further buys/sells
+//    - migrate_pool moves real SOL + remaining tokens (plus the vault's
+//      liquidity reserve) into an x*y=k pool; LP is locked forever
+//    - cp_swap trades against that pool in both directions afterwards
+//    - The rest of the vault reserve (supply not on the curve) follows the
+//      LaunchConfig split: creator allocation (vested: cliff + linear, claimed
+//      by the minter via claim_vested) and burn
 //    - Price calculated using virtual reserves
 //
+// 5. EMERGENCY CONTROLS:
+//    - Authority can pause minting / pool creation / buys / sells / migration
+//      protocol-wide (ProtocolState.paused) or halt a single pool (AmmPool.halted)
+//
+// 6. BURNS:
+//    - Any holder can burn their own tokens (burn_meme_tokens)
+//    - Authority can spend fee vault SOL buying on the curve and burn the
+//      output (buyback_and_burn)
+//    - Cumulative burned amount (including burn_reserve) tracked on
+//      MemeTokenState
+//
 // VIRTUAL RESERVES SYSTEM:
 // - Real reserves: Track actual SOL/tokens in pool
 // - Virtual reserves: Used for price calculation
 // - Creates instant market cap without massive capital
+// - Pricing lives in the `curve` module (checked math, rounds for the pool)
+// - AmmPool keeps spot price, FDV market cap, progress and volume up to date
 //
 // ===========================================================================
 
@@ -37,7 +74,15 @@ declare_id!("AvFrAyZRux3AUC3U1p82QuGzysqq85jdFfVzMB8rkqSy");
 // Mint fee: 0.01 SOL (10,000,000 lamports)
 pub const MINT_FEE_LAMPORTS: u64 = 10_000_000;
 
-// FIXED Pool Parameters (pump.fun style)
+// Token-2022 metadata limits
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+// Total supply minted per meme token
+pub const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000; // 1 billion tokens
+
+// Default pool parameters (pump.fun style) for new LaunchConfigs
 pub const FIXED_INITIAL_SOL: u64 = 20_000_000; // 0.02 SOL
 pub const FIXED_INITIAL_TOKENS: u64 = 800_000_000_000_000_000; // 800M tokens
 
@@ -49,8 +94,55 @@ pub const INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000_000; // 1.
 pub const AMM_POOL_SEED: &[u8] = b"amm_pool";
 pub const POOL_SOL_VAULT_SEED: &[u8] = b"pool_sol_vault";
 pub const POOL_TOKEN_VAULT_SEED: &[u8] = b"pool_token_vault";
-pub const FEE_NUMERATOR: u64 = 3;
-pub const FEE_DENOMINATOR: u64 = 1000;
+pub const CREATOR_FEE_VAULT_SEED: &[u8] = b"creator_fee_vault";
+
+// Post-graduation constant-product pool
+pub const CP_POOL_SEED: &[u8] = b"cp_pool";
+pub const CP_SOL_VAULT_SEED: &[u8] = b"cp_sol_vault";
+pub const CP_TOKEN_VAULT_SEED: &[u8] = b"cp_token_vault";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const LP_LOCK_SEED: &[u8] = b"lp_lock";
+pub const CP_SWAP_FEE_BPS: u16 = 25; // 0.25%, stays in the pool (LP is locked)
+
+// Trading fee (basis points, defaults for new LaunchConfigs)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const DEFAULT_TRADE_FEE_BPS: u16 = 30; // 0.3%
+pub const DEFAULT_CREATOR_FEE_BPS: u16 = 10; // 0.1% to the original minter
+pub const MAX_TRADE_FEE_BPS: u16 = 500; // 5% hard cap (protocol + creator)
+
+// Graduation: curve completes once this much real SOL has been raised
+pub const DEFAULT_GRADUATION_SOL_THRESHOLD: u64 = 85_000_000_000; // 85 SOL
+
+// Anti-snipe: per-wallet buy cap for the first slots after pool creation
+pub const LAUNCH_BUY_SEED: &[u8] = b"launch_buy";
+pub const DEFAULT_LAUNCH_WINDOW_SLOTS: u64 = 150; // ~1 minute
+pub const DEFAULT_MAX_BUY_PER_WALLET_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+
+// Emergency pause bits (ProtocolState.paused)
+pub const PAUSE_MINT: u8 = 1 << 0;
+pub const PAUSE_POOL_CREATION: u8 = 1 << 1;
+pub const PAUSE_BUYS: u8 = 1 << 2;
+pub const PAUSE_SELLS: u8 = 1 << 3;
+pub const PAUSE_MIGRATION: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 =
+    PAUSE_MINT | PAUSE_POOL_CREATION | PAUSE_BUYS | PAUSE_SELLS | PAUSE_MIGRATION;
+
+// Admin-managed pool presets ("standard", "degen", ...)
+pub const LAUNCH_CONFIG_SEED: &[u8] = b"launch_config";
+pub const DEFAULT_LAUNCH_CONFIG_ID: u16 = 0; // upgraded legacy pools use this one
+
+// Split of the vault reserve (supply not sold on the curve), in bps.
+// Default keeps everything as graduation liquidity.
+pub const DEFAULT_RESERVE_LIQUIDITY_BPS: u16 = 10_000;
+pub const DEFAULT_RESERVE_CREATOR_BPS: u16 = 0;
+pub const DEFAULT_RESERVE_BURN_BPS: u16 = 0;
+
+// Creator allocation vests from mint time: nothing before the cliff, then
+// linear until the end of the duration
+pub const VESTING_SEED: &[u8] = b"vesting";
+pub const VESTING_TOKEN_SEED: &[u8] = b"vesting_tokens";
+pub const DEFAULT_VESTING_CLIFF_SECONDS: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const DEFAULT_VESTING_DURATION_SECONDS: i64 = 90 * 24 * 60 * 60; // 90 days
 
 // ==================== PROGRAM ====================
 
@@ -60,36 +152,317 @@ pub mod meme_launchpad {
 
     // ==================== PROTOCOL MANAGEMENT ====================
 
+    /// One-time setup, callable only by the program's upgrade authority
     pub fn initialize_protocol_state(ctx: Context<InitializeProtocolState>) -> Result<()> {
         let state = &mut ctx.accounts.protocol_state;
+
+        // `init_if_needed` lets us surface a clear error instead of the
+        // system program's "account already in use"
+        require_keys_eq!(
+            state.authority,
+            Pubkey::default(),
+            ErrorCode::ProtocolAlreadyInitialized
+        );
+
         state.authority = ctx.accounts.authority.key();
-        state.fee_lamports = MINT_FEE_LAMPORTS; // Fixed at 0.01 SOL
+        state.fee_lamports = MINT_FEE_LAMPORTS; // Defaults to 0.01 SOL
+        state.trade_fee_bps = DEFAULT_TRADE_FEE_BPS;
+        state.creator_fee_bps = DEFAULT_CREATOR_FEE_BPS;
+        state.graduation_sol_threshold = DEFAULT_GRADUATION_SOL_THRESHOLD;
+        state.launch_window_slots = DEFAULT_LAUNCH_WINDOW_SLOTS;
+        state.max_buy_per_wallet_lamports = DEFAULT_MAX_BUY_PER_WALLET_LAMPORTS;
         state.bump = ctx.bumps.protocol_state;
 
         emit!(ProtocolInitialized {
             authority: ctx.accounts.authority.key(),
             fee_vault: ctx.accounts.fee_vault.key(),
             fee_lamports: MINT_FEE_LAMPORTS,
+            trade_fee_bps: DEFAULT_TRADE_FEE_BPS,
+            creator_fee_bps: DEFAULT_CREATOR_FEE_BPS,
+            graduation_sol_threshold: DEFAULT_GRADUATION_SOL_THRESHOLD,
+            launch_window_slots: DEFAULT_LAUNCH_WINDOW_SLOTS,
+            max_buy_per_wallet_lamports: DEFAULT_MAX_BUY_PER_WALLET_LAMPORTS,
         });
 
         Ok(())
     }
 
-    // ==================== TOKEN MINTING ====================
+    /// Update protocol configuration (authority only)
+    /// Fields left as `None` keep their current value.
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolState>,
+        params: ProtocolConfigParams,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.protocol_state;
+
+        if let Some(fee_lamports) = params.fee_lamports {
+            state.fee_lamports = fee_lamports;
+        }
+        if let Some(trade_fee_bps) = params.trade_fee_bps {
+            state.trade_fee_bps = trade_fee_bps;
+        }
+        if let Some(creator_fee_bps) = params.creator_fee_bps {
+            state.creator_fee_bps = creator_fee_bps;
+        }
+        if let Some(graduation_sol_threshold) = params.graduation_sol_threshold {
+            state.graduation_sol_threshold = graduation_sol_threshold;
+        }
+        if let Some(launch_window_slots) = params.launch_window_slots {
+            state.launch_window_slots = launch_window_slots;
+        }
+        if let Some(max_buy_per_wallet_lamports) = params.max_buy_per_wallet_lamports {
+            state.max_buy_per_wallet_lamports = max_buy_per_wallet_lamports;
+        }
+        require!(
+            (state.trade_fee_bps as u32) + (state.creator_fee_bps as u32)
+                <= MAX_TRADE_FEE_BPS as u32,
+            ErrorCode::FeeTooHigh
+        );
 
-    pub fn mint_meme_token(ctx: Context<MintMemeToken>, meme_id: [u8; 32]) -> Result<()> {
+        emit!(ProtocolConfigUpdated {
+            authority: state.authority,
+            fee_lamports: state.fee_lamports,
+            trade_fee_bps: state.trade_fee_bps,
+            creator_fee_bps: state.creator_fee_bps,
+            graduation_sol_threshold: state.graduation_sol_threshold,
+            launch_window_slots: state.launch_window_slots,
+            max_buy_per_wallet_lamports: state.max_buy_per_wallet_lamports,
+        });
+
+        Ok(())
+    }
+
+    /// Create a pool preset (authority only)
+    /// Fields left as `None` take the protocol defaults.
+    pub fn create_launch_config(
+        ctx: Context<CreateLaunchConfig>,
+        config_id: u16,
+        params: LaunchConfigParams,
+    ) -> Result<()> {
         let protocol_state = &ctx.accounts.protocol_state;
+        let config = &mut ctx.accounts.launch_config;
+
+        config.config_id = config_id;
+        config.initial_sol = params.initial_sol.unwrap_or(FIXED_INITIAL_SOL);
+        config.curve_tokens = params.curve_tokens.unwrap_or(FIXED_INITIAL_TOKENS);
+        config.virtual_sol_reserve = params
+            .virtual_sol_reserve
+            .unwrap_or(INITIAL_VIRTUAL_SOL_RESERVES);
+        config.virtual_token_reserve = params
+            .virtual_token_reserve
+            .unwrap_or(INITIAL_VIRTUAL_TOKEN_RESERVES);
+        config.graduation_sol_threshold = params
+            .graduation_sol_threshold
+            .unwrap_or(protocol_state.graduation_sol_threshold);
+        config.trade_fee_bps = params.trade_fee_bps.unwrap_or(protocol_state.trade_fee_bps);
+        config.creator_fee_bps = params
+            .creator_fee_bps
+            .unwrap_or(protocol_state.creator_fee_bps);
+        config.reserve_liquidity_bps = params
+            .reserve_liquidity_bps
+            .unwrap_or(DEFAULT_RESERVE_LIQUIDITY_BPS);
+        config.reserve_creator_bps = params
+            .reserve_creator_bps
+            .unwrap_or(DEFAULT_RESERVE_CREATOR_BPS);
+        config.reserve_burn_bps = params.reserve_burn_bps.unwrap_or(DEFAULT_RESERVE_BURN_BPS);
+        config.vesting_cliff_seconds = params
+            .vesting_cliff_seconds
+            .unwrap_or(DEFAULT_VESTING_CLIFF_SECONDS);
+        config.vesting_duration_seconds = params
+            .vesting_duration_seconds
+            .unwrap_or(DEFAULT_VESTING_DURATION_SECONDS);
+        config.curve_type = params.curve_type.unwrap_or(CurveType::ConstantProduct);
+        config.bump = ctx.bumps.launch_config;
+        config.validate()?;
+
+        emit_launch_config_updated(config.key(), config);
+
+        Ok(())
+    }
+
+    /// Update a pool preset (authority only). Live pools keep the values
+    /// they snapshotted at creation.
+    /// Fields left as `None` keep their current value.
+    pub fn update_launch_config(
+        ctx: Context<UpdateLaunchConfig>,
+        params: LaunchConfigParams,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.launch_config;
+
+        if let Some(initial_sol) = params.initial_sol {
+            config.initial_sol = initial_sol;
+        }
+        if let Some(curve_tokens) = params.curve_tokens {
+            config.curve_tokens = curve_tokens;
+        }
+        if let Some(virtual_sol_reserve) = params.virtual_sol_reserve {
+            config.virtual_sol_reserve = virtual_sol_reserve;
+        }
+        if let Some(virtual_token_reserve) = params.virtual_token_reserve {
+            config.virtual_token_reserve = virtual_token_reserve;
+        }
+        if let Some(graduation_sol_threshold) = params.graduation_sol_threshold {
+            config.graduation_sol_threshold = graduation_sol_threshold;
+        }
+        if let Some(trade_fee_bps) = params.trade_fee_bps {
+            config.trade_fee_bps = trade_fee_bps;
+        }
+        if let Some(creator_fee_bps) = params.creator_fee_bps {
+            config.creator_fee_bps = creator_fee_bps;
+        }
+        if let Some(reserve_liquidity_bps) = params.reserve_liquidity_bps {
+            config.reserve_liquidity_bps = reserve_liquidity_bps;
+        }
+        if let Some(reserve_creator_bps) = params.reserve_creator_bps {
+            config.reserve_creator_bps = reserve_creator_bps;
+        }
+        if let Some(reserve_burn_bps) = params.reserve_burn_bps {
+            config.reserve_burn_bps = reserve_burn_bps;
+        }
+        if let Some(vesting_cliff_seconds) = params.vesting_cliff_seconds {
+            config.vesting_cliff_seconds = vesting_cliff_seconds;
+        }
+        if let Some(vesting_duration_seconds) = params.vesting_duration_seconds {
+            config.vesting_duration_seconds = vesting_duration_seconds;
+        }
+        if let Some(curve_type) = params.curve_type {
+            config.curve_type = curve_type;
+        }
+        config.validate()?;
+
+        emit_launch_config_updated(config.key(), config);
+
+        Ok(())
+    }
+
+    /// Emergency switch (authority only): sets the PAUSE_* bits.
+    /// `reason` is an off-chain code carried in the event.
+    pub fn set_paused(ctx: Context<UpdateProtocolState>, paused: u8, reason: u16) -> Result<()> {
+        require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+        let state = &mut ctx.accounts.protocol_state;
+        let previous = state.paused;
+        state.paused = paused;
+
+        emit!(ProtocolPauseUpdated {
+            authority: state.authority,
+            previous,
+            paused,
+            reason,
+        });
+
+        Ok(())
+    }
+
+    /// Halt or resume trading on a single pool (authority only)
+    /// Also covers the constant-product pool it migrates to.
+    pub fn set_pool_halted(ctx: Context<SetPoolHalted>, halted: bool, reason: u16) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.halted = halted;
+
+        emit!(PoolHaltUpdated {
+            pool: pool.key(),
+            token_mint: pool.token_mint,
+            authority: ctx.accounts.authority.key(),
+            halted,
+            reason,
+        });
+
+        Ok(())
+    }
+
+    /// Step 1 of authority handover: current authority nominates a successor
+    pub fn propose_authority(
+        ctx: Context<UpdateProtocolState>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.protocol_state;
+        state.pending_authority = new_authority;
+
+        emit!(AuthorityProposed {
+            authority: state.authority,
+            pending_authority: new_authority,
+        });
+
+        Ok(())
+    }
+
+    /// Step 2 of authority handover: nominee signs to take over
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let state = &mut ctx.accounts.protocol_state;
+        let old_authority = state.authority;
+
+        state.authority = ctx.accounts.pending_authority.key();
+        state.pending_authority = Pubkey::default();
+
+        emit!(AuthorityTransferred {
+            old_authority,
+            new_authority: state.authority,
+        });
+
+        Ok(())
+    }
+
+    /// Move collected fees out of the fee vault (authority only)
+    /// The vault always keeps its rent-exempt minimum.
+    pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidWithdrawAmount);
+
+        let fee_vault = &ctx.accounts.fee_vault;
+        let rent_exempt_min = Rent::get()?.minimum_balance(0);
+        let available = fee_vault.lamports().saturating_sub(rent_exempt_min);
+        require!(amount <= available, ErrorCode::InsufficientFeeVaultBalance);
+
+        let fee_vault_seeds: &[&[u8]] = &[b"fee_vault", &[ctx.bumps.fee_vault]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: fee_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                &[fee_vault_seeds],
+            ),
+            amount,
+        )?;
+
+        emit!(FeesWithdrawn {
+            authority: ctx.accounts.authority.key(),
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+            remaining: ctx.accounts.fee_vault.lamports(),
+        });
+
+        Ok(())
+    }
+
+    // ==================== TOKEN MINTING ====================
 
+    pub fn mint_meme_token(
+        ctx: Context<MintMemeToken>,
+        meme_id: [u8; 32],
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.protocol_state.require_not_paused(PAUSE_MINT)?;
         require!(
             !ctx.accounts.meme_token_state.is_initialized,
             ErrorCode::MemeAlreadyMinted
         );
+        validate_metadata(&name, &symbol, &uri)?;
+
+        let mint_accounts = ctx.accounts.mint_accounts(ctx.bumps.vault);
 
         // Transfer protocol fee
-        transfer_native_sol_fee(&ctx.accounts, protocol_state.fee_lamports)?;
+        transfer_native_sol_fee(&mint_accounts, ctx.accounts.protocol_state.fee_lamports)?;
+
+        // Write name/symbol/uri into the mint's token-metadata extension
+        initialize_token_metadata(&mint_accounts, name.clone(), symbol.clone(), uri.clone())?;
 
         // Distribute supply (100% to vault, 0% to minter)
-        distribute_supply(&ctx)?;
+        distribute_supply(&mint_accounts)?;
 
         // Update MemeTokenState
         let meme_token_state = &mut ctx.accounts.meme_token_state;
@@ -104,123 +477,188 @@ pub mod meme_launchpad {
             meme_id,
             minter: ctx.accounts.minter.key(),
             mint_addr: ctx.accounts.mint.key(),
+            name,
+            symbol,
+            uri,
         });
 
         Ok(())
     }
 
-    // ==================== AMM POOL FUNCTIONS ====================
+    /// Mint + pool creation (+ optional creator buy) in a single transaction
+    /// Same fee, supply split and LaunchConfig pool parameters as calling
+    /// mint_meme_token and initialize_amm_pool separately.
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch_meme(
+        ctx: Context<LaunchMeme>,
+        meme_id: [u8; 32],
+        name: String,
+        symbol: String,
+        uri: String,
+        initial_buy_sol: u64,
+        min_initial_buy_tokens: u64,
+    ) -> Result<()> {
+        let protocol_state = &ctx.accounts.protocol_state;
+        protocol_state.require_not_paused(PAUSE_MINT)?;
+        protocol_state.require_not_paused(PAUSE_POOL_CREATION)?;
+        if initial_buy_sol > 0 {
+            protocol_state.require_not_paused(PAUSE_BUYS)?;
+        }
+        validate_metadata(&name, &symbol, &uri)?;
 
-    /// Initialize pool with FIXED parameters (pump.fun style)
-    /// User just calls this function, no parameters needed!
-    pub fn initialize_amm_pool(ctx: Context<InitializePool>) -> Result<()> {
-        let token_mint_key = ctx.accounts.token_mint.key();
+        let fee_bps = ctx.accounts.launch_config.trade_fee_bps;
+        let creator_fee_bps = ctx.accounts.launch_config.creator_fee_bps;
 
-        // Derive PDAs
-        let (sol_vault_pda, _) = Pubkey::find_program_address(
-            &[POOL_SOL_VAULT_SEED, token_mint_key.as_ref()],
-            ctx.program_id,
-        );
-        let (token_vault_pda, _) = Pubkey::find_program_address(
-            &[POOL_TOKEN_VAULT_SEED, token_mint_key.as_ref()],
-            ctx.program_id,
+        // 1. Mint
+        let mint_accounts = ctx.accounts.mint_accounts(ctx.bumps.vault);
+        transfer_native_sol_fee(&mint_accounts, protocol_state.fee_lamports)?;
+        initialize_token_metadata(&mint_accounts, name.clone(), symbol.clone(), uri.clone())?;
+        distribute_supply(&mint_accounts)?;
+
+        let token_mint_key = ctx.accounts.mint.key();
+        let pool_key = ctx.accounts.pool.key();
+
+        let meme_token_state = &mut ctx.accounts.meme_token_state;
+        meme_token_state.meme_id = meme_id;
+        meme_token_state.mint = token_mint_key;
+        meme_token_state.minter = ctx.accounts.creator.key();
+        meme_token_state.created_at = Clock::get()?.unix_timestamp;
+        meme_token_state.is_initialized = true;
+        meme_token_state.bump = ctx.bumps.meme_token_state;
+        meme_token_state.pool = pool_key;
+
+        // 2. Pool
+        let pool_seed_accounts = ctx.accounts.pool_seed_accounts(ctx.bumps.vault);
+        init_pool_state(
+            &mut ctx.accounts.pool,
+            &pool_seed_accounts,
+            ctx.bumps.pool,
+            &ctx.accounts.protocol_state,
+            &ctx.accounts.launch_config,
+        )?;
+        init_creator_fee_vault(
+            &mut ctx.accounts.creator_fee_vault,
+            token_mint_key,
+            ctx.bumps.creator_fee_vault,
         );
+        seed_pool(&pool_seed_accounts, &ctx.accounts.launch_config)?;
 
-        // Initialize pool state with FIXED parameters
-        let pool = &mut ctx.accounts.pool;
-        pool.token_mint = token_mint_key;
-        pool.sol_vault = sol_vault_pda;
-        pool.token_vault = token_vault_pda;
+        // 3. Optional creator buy
+        let mut initial_buy = SwapOutcome::default();
+        if initial_buy_sol > 0 {
+            let buy_accounts = ctx.accounts.buy_accounts();
+            initial_buy = execute_buy(
+                &buy_accounts,
+                &mut ctx.accounts.pool,
+                &mut ctx.accounts.creator_fee_vault,
+                fee_bps,
+                creator_fee_bps,
+                initial_buy_sol,
+                min_initial_buy_tokens,
+            )?;
+        }
 
-        // Real reserves (actual amounts in vaults)
-        pool.real_sol_reserve = FIXED_INITIAL_SOL;
-        pool.real_token_reserve = FIXED_INITIAL_TOKENS;
+        let pool = &mut ctx.accounts.pool;
 
-        // Virtual reserves (for price calculation) ⭐
-        pool.virtual_sol_reserve = INITIAL_VIRTUAL_SOL_RESERVES + FIXED_INITIAL_SOL;
-        pool.virtual_token_reserve = INITIAL_VIRTUAL_TOKEN_RESERVES;
+        emit!(MemeLaunched {
+            meme_id,
+            minter: ctx.accounts.creator.key(),
+            mint_addr: token_mint_key,
+            name,
+            symbol,
+            uri,
+            pool: pool_key,
+            launch_config: pool.launch_config,
+            curve_type: pool.curve_type,
+            real_sol: pool.real_sol_reserve,
+            real_tokens: pool.real_token_reserve,
+            virtual_sol: pool.virtual_sol_reserve,
+            virtual_tokens: pool.virtual_token_reserve,
+            initial_buy_sol: initial_buy.sol_amount,
+            initial_buy_tokens: initial_buy.token_amount,
+        });
 
-        pool.bump = ctx.bumps.pool;
-        pool.is_initialized = true;
+        check_graduation(pool, pool_key);
 
-        msg!("🚀 Pool initialized with FIXED parameters:");
-        msg!("   Real SOL: {} lamports (0.02 SOL)", FIXED_INITIAL_SOL);
-        msg!(
-            "   Real Tokens: {} base units (800M tokens)",
-            FIXED_INITIAL_TOKENS
-        );
-        msg!(
-            "   Virtual SOL: {} lamports (30.02 SOL)",
-            pool.virtual_sol_reserve
-        );
-        msg!(
-            "   Virtual Tokens: {} base units (1.073B tokens)",
-            pool.virtual_token_reserve
-        );
+        Ok(())
+    }
 
-        // Calculate and log initial market cap
-        let initial_market_cap_lamports = pool.virtual_sol_reserve;
-        msg!(
-            "   Initial Market Cap: {} lamports (~{} SOL)",
-            initial_market_cap_lamports,
-            initial_market_cap_lamports / 1_000_000_000
-        );
+    // ==================== AMM POOL FUNCTIONS ====================
 
-        // Transfer FIXED amount of tokens FROM minting vault TO pool
-        msg!(
-            "📦 Transferring {} tokens from vault to pool...",
-            FIXED_INITIAL_TOKENS
-        );
+    /// Initialize pool from a LaunchConfig preset (pump.fun style)
+    /// Only the meme's minter can call this. Optionally makes a dev buy in
+    /// the same instruction (pass 0 to skip), ahead of the anti-snipe
+    /// launch window.
+    pub fn initialize_amm_pool(
+        ctx: Context<InitializePool>,
+        dev_buy_sol: u64,
+        min_dev_buy_tokens: u64,
+    ) -> Result<()> {
+        let protocol_state = &ctx.accounts.protocol_state;
+        protocol_state.require_not_paused(PAUSE_POOL_CREATION)?;
+        if dev_buy_sol > 0 {
+            protocol_state.require_not_paused(PAUSE_BUYS)?;
+        }
 
-        let vault_seeds = &[b"vault", token_mint_key.as_ref(), &[ctx.bumps.vault]];
-        let signer_seeds = &[&vault_seeds[..]];
+        let token_mint_key = ctx.accounts.token_mint.key();
 
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.vault_token_account.to_account_info(),
-            to: ctx.accounts.token_vault.to_account_info(),
-            mint: ctx.accounts.token_mint.to_account_info(),
-            authority: ctx.accounts.vault.to_account_info(),
-        };
-        token::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                cpi_accounts,
-                signer_seeds,
-            ),
-            FIXED_INITIAL_TOKENS,
-            9,
+        // Initialize pool state from the launch config preset
+        let pool_seed_accounts = ctx.accounts.pool_seed_accounts(ctx.bumps.vault);
+        init_pool_state(
+            &mut ctx.accounts.pool,
+            &pool_seed_accounts,
+            ctx.bumps.pool,
+            &ctx.accounts.protocol_state,
+            &ctx.accounts.launch_config,
         )?;
 
-        msg!("✅ Tokens transferred successfully!");
+        // Link token → pool on-chain
+        let pool_key = ctx.accounts.pool.key();
+        ctx.accounts.meme_token_state.pool = pool_key;
 
-        // Transfer FIXED amount of SOL from initializer to pool
-        msg!(
-            "💰 Transferring {} lamports (0.02 SOL) from user to pool...",
-            FIXED_INITIAL_SOL
+        init_creator_fee_vault(
+            &mut ctx.accounts.creator_fee_vault,
+            token_mint_key,
+            ctx.bumps.creator_fee_vault,
         );
 
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.initializer.to_account_info(),
-                    to: ctx.accounts.sol_vault.to_account_info(),
-                },
-            ),
-            FIXED_INITIAL_SOL,
-        )?;
+        // Move curve tokens from the minting vault and initial SOL from the initializer
+        seed_pool(&pool_seed_accounts, &ctx.accounts.launch_config)?;
 
-        msg!("✅ SOL transferred successfully!");
+        // Optional dev buy (not subject to the launch window cap - only the
+        // minter gets here, see InitializePool)
+        let mut dev_buy = SwapOutcome::default();
+        if dev_buy_sol > 0 {
+            let fee_bps = ctx.accounts.pool.trade_fee_bps;
+            let creator_fee_bps = ctx.accounts.pool.creator_fee_bps;
+            let buy_accounts = ctx.accounts.buy_accounts();
+            dev_buy = execute_buy(
+                &buy_accounts,
+                &mut ctx.accounts.pool,
+                &mut ctx.accounts.creator_fee_vault,
+                fee_bps,
+                creator_fee_bps,
+                dev_buy_sol,
+                min_dev_buy_tokens,
+            )?;
+        }
 
+        let pool = &mut ctx.accounts.pool;
         emit!(PoolInitialized {
-            pool: pool.key(),
+            pool: pool_key,
             token_mint: token_mint_key,
-            real_sol: FIXED_INITIAL_SOL,
-            real_tokens: FIXED_INITIAL_TOKENS,
+            launch_config: pool.launch_config,
+            curve_type: pool.curve_type,
+            real_sol: ctx.accounts.launch_config.initial_sol,
+            real_tokens: pool.curve_tokens,
             virtual_sol: pool.virtual_sol_reserve,
             virtual_tokens: pool.virtual_token_reserve,
+            dev_buy_sol: dev_buy.sol_amount,
+            dev_buy_tokens: dev_buy.token_amount,
         });
 
+        check_graduation(pool, pool_key);
+
         Ok(())
     }
 
@@ -229,410 +667,3411 @@ pub mod meme_launchpad {
         sol_amount: u64,
         min_token_amount: u64,
     ) -> Result<()> {
-        let pool_account_info = ctx.accounts.pool.to_account_info();
-        let pool = &mut ctx.accounts.pool;
-
-        require!(sol_amount > 0, AmmError::InvalidAmount);
-        require!(pool.is_initialized, AmmError::PoolNotInitialized);
-
-        // Calculate output with fee using VIRTUAL reserves
-        let fee_amount = (sol_amount as u128)
-            .checked_mul(FEE_NUMERATOR as u128)
-            .unwrap()
-            .checked_div(FEE_DENOMINATOR as u128)
-            .unwrap() as u64;
-        let sol_amount_after_fee = sol_amount.checked_sub(fee_amount).unwrap();
-
-        // ⭐ Use VIRTUAL reserves for price calculation
-        let token_amount = (sol_amount_after_fee as u128)
-            .checked_mul(pool.virtual_token_reserve as u128)
-            .unwrap()
-            .checked_div(
-                (pool.virtual_sol_reserve as u128)
-                    .checked_add(sol_amount_after_fee as u128)
-                    .unwrap(),
-            ).unwrap() as u64;
-
-        require!(token_amount >= min_token_amount, AmmError::SlippageExceeded);
-        require!(
-            token_amount < pool.real_token_reserve,
-            AmmError::InsufficientLiquidity
-        );
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, PAUSE_BUYS)?;
 
-        msg!(
-            "💫 Swapping {} SOL for {} tokens (fee: {})",
-            sol_amount,
-            token_amount,
-            fee_amount
-        );
-        msg!(
-            "   Price: {} virtual SOL / {} virtual tokens",
-            pool.virtual_sol_reserve,
-            pool.virtual_token_reserve
-        );
+        let fee_bps = ctx.accounts.pool.trade_fee_bps;
+        let creator_fee_bps = ctx.accounts.pool.creator_fee_bps;
+        let pool_key = ctx.accounts.pool.key();
 
-        // Transfer SOL
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.sol_vault.to_account_info(),
-                },
-            ),
+        let buy_accounts = ctx.accounts.buy_accounts();
+        let outcome = execute_buy(
+            &buy_accounts,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.creator_fee_vault,
+            fee_bps,
+            creator_fee_bps,
             sol_amount,
+            min_token_amount,
         )?;
 
-        // Transfer tokens
-        let token_mint_key = pool.token_mint;
-        let pool_bump = pool.bump;
-        let pool_key = pool.key();
-        let user_key = ctx.accounts.user.key();
-        let seeds = &[AMM_POOL_SEED, token_mint_key.as_ref(), &[pool_bump]];
-
-        token::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.token_vault.to_account_info(),
-                    to: ctx.accounts.user_token_account.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    authority: pool_account_info,
-                },
-                &[&seeds[..]],
-            ),
-            token_amount,
-            9,
-        )?;
-
-        // Update BOTH real and virtual reserves
-        pool.real_sol_reserve = pool.real_sol_reserve.checked_add(sol_amount).unwrap();
-        pool.real_token_reserve = pool.real_token_reserve.checked_sub(token_amount).unwrap();
-        pool.virtual_sol_reserve = pool.virtual_sol_reserve.checked_add(sol_amount).unwrap();
-        pool.virtual_token_reserve = pool
-            .virtual_token_reserve
-            .checked_sub(token_amount)
-            .unwrap();
-
-        msg!(
-            "📊 New reserves - Real: {} SOL / {} tokens, Virtual: {} SOL / {} tokens",
-            pool.real_sol_reserve,
-            pool.real_token_reserve,
-            pool.virtual_sol_reserve,
-            pool.virtual_token_reserve
-        );
+        ctx.accounts
+            .record_launch_buy(ctx.bumps.launch_buys, outcome.sol_amount)?;
 
         emit!(SwapExecuted {
             pool: pool_key,
-            user: user_key,
+            user: ctx.accounts.user.key(),
             input_token: "SOL".to_string(),
-            input_amount: sol_amount,
-            output_amount: token_amount,
-            fee: fee_amount,
+            input_amount: outcome.sol_amount,
+            output_amount: outcome.token_amount,
+            protocol_fee: outcome.protocol_fee,
+            creator_fee: outcome.creator_fee,
+            fee_recipient: ctx.accounts.fee_vault.key(),
         });
 
+        check_graduation(&mut ctx.accounts.pool, pool_key);
+
         Ok(())
     }
 
-    pub fn swap_tokens_to_sol(
-        ctx: Context<SwapTokensForSol>,
+    /// Buy an exact number of tokens, paying at most `max_sol_in` (fees included)
+    pub fn buy_exact_tokens(
+        ctx: Context<SwapSolForTokens>,
         token_amount: u64,
-        min_sol_amount: u64,
+        max_sol_in: u64,
     ) -> Result<()> {
-        let pool = &mut ctx.accounts.pool;
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, PAUSE_BUYS)?;
+
+        let fee_bps = ctx.accounts.pool.trade_fee_bps;
+        let creator_fee_bps = ctx.accounts.pool.creator_fee_bps;
+        let pool_key = ctx.accounts.pool.key();
+        let pool = &ctx.accounts.pool;
 
         require!(token_amount > 0, AmmError::InvalidAmount);
         require!(pool.is_initialized, AmmError::PoolNotInitialized);
-
-        // Calculate output with fee using VIRTUAL reserves
-        let fee_amount = (token_amount as u128)
-            .checked_mul(FEE_NUMERATOR as u128)
-            .unwrap()
-            .checked_div(FEE_DENOMINATOR as u128)
-            .unwrap() as u64;
-        let token_amount_after_fee = token_amount.checked_sub(fee_amount).unwrap();
-
-        // ⭐ Use VIRTUAL reserves for price calculation
-        let sol_amount = (token_amount_after_fee as u128)
-            .checked_mul(pool.virtual_sol_reserve as u128)
-            .unwrap()
-            .checked_div(
-                (pool.virtual_token_reserve as u128)
-                    .checked_add(token_amount_after_fee as u128)
-                    .unwrap(),
-            ).unwrap() as u64;
-
-        require!(sol_amount >= min_sol_amount, AmmError::SlippageExceeded);
+        require!(!pool.is_complete, AmmError::CurveComplete);
         require!(
-            sol_amount < pool.real_sol_reserve,
+            token_amount <= pool.real_token_reserve,
             AmmError::InsufficientLiquidity
         );
 
-        msg!(
-            "💫 Swapping {} tokens for {} SOL (fee: {})",
-            token_amount,
-            sol_amount,
-            fee_amount
-        );
+        let outcome = quote_buy_exact_tokens(pool, fee_bps, creator_fee_bps, token_amount)?;
+        require!(outcome.sol_amount <= max_sol_in, AmmError::SlippageExceeded);
 
-        // Transfer tokens from user to pool
-        token::transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.user_token_account.to_account_info(),
-                    to: ctx.accounts.token_vault.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            token_amount,
-            9,
+        let buy_accounts = ctx.accounts.buy_accounts();
+        settle_buy(
+            &buy_accounts,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.creator_fee_vault,
+            &outcome,
         )?;
 
-        // Transfer SOL from vault to user using proper system program CPI
-        let token_mint_key = pool.token_mint;
-        let pool_bump = pool.bump;
+        ctx.accounts
+            .record_launch_buy(ctx.bumps.launch_buys, outcome.sol_amount)?;
 
-        let sol_vault_seeds = &[POOL_SOL_VAULT_SEED, token_mint_key.as_ref()];
-        let (sol_vault_pda, sol_vault_bump) =
-            Pubkey::find_program_address(sol_vault_seeds, ctx.program_id);
+        emit!(SwapExecuted {
+            pool: pool_key,
+            user: ctx.accounts.user.key(),
+            input_token: "SOL".to_string(),
+            input_amount: outcome.sol_amount,
+            output_amount: outcome.token_amount,
+            protocol_fee: outcome.protocol_fee,
+            creator_fee: outcome.creator_fee,
+            fee_recipient: ctx.accounts.fee_vault.key(),
+        });
 
-        require_keys_eq!(
-            sol_vault_pda,
-            ctx.accounts.sol_vault.key(),
-            AmmError::InvalidVault
-        );
+        check_graduation(&mut ctx.accounts.pool, pool_key);
 
-        let vault_signer_seeds = &[
-            POOL_SOL_VAULT_SEED,
-            token_mint_key.as_ref(),
-            &[sol_vault_bump],
-        ];
-        let vault_signer = &[&vault_signer_seeds[..]];
+        Ok(())
+    }
 
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.user.to_account_info(),
-                },
-                vault_signer,
-            ),
-            sol_amount,
-        )?;
+    pub fn swap_tokens_to_sol(
+        ctx: Context<SwapTokensForSol>,
+        token_amount: u64,
+        min_sol_amount: u64,
+    ) -> Result<()> {
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, PAUSE_SELLS)?;
 
-        // Update BOTH real and virtual reserves
-        pool.real_sol_reserve = pool.real_sol_reserve.checked_sub(sol_amount).unwrap();
-        pool.real_token_reserve = pool.real_token_reserve.checked_add(token_amount).unwrap();
-        pool.virtual_sol_reserve = pool.virtual_sol_reserve.checked_sub(sol_amount).unwrap();
-        pool.virtual_token_reserve = pool
-            .virtual_token_reserve
-            .checked_add(token_amount)
-            .unwrap();
+        let fee_bps = ctx.accounts.pool.trade_fee_bps;
+        let creator_fee_bps = ctx.accounts.pool.creator_fee_bps;
+        let pool_key = ctx.accounts.pool.key();
+        let pool = &ctx.accounts.pool;
+
+        require!(token_amount > 0, AmmError::InvalidAmount);
+        require!(pool.is_initialized, AmmError::PoolNotInitialized);
+        require!(!pool.is_complete, AmmError::CurveComplete);
 
-        let pool_key = pool.key();
-        let user_key = ctx.accounts.user.key();
+        let outcome = quote_sell_exact_tokens(pool, fee_bps, creator_fee_bps, token_amount)?;
+        require!(outcome.sol_amount >= min_sol_amount, AmmError::SlippageExceeded);
 
-        msg!(
-            "📊 New reserves - Real: {} SOL / {} tokens, Virtual: {} SOL / {} tokens",
-            pool.real_sol_reserve,
-            pool.real_token_reserve,
-            pool.virtual_sol_reserve,
-            pool.virtual_token_reserve
-        );
+        let sell_accounts = ctx.accounts.sell_accounts(ctx.bumps.sol_vault);
+        settle_sell(
+            &sell_accounts,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.creator_fee_vault,
+            &outcome,
+        )?;
 
         emit!(SwapExecuted {
             pool: pool_key,
-            user: user_key,
+            user: ctx.accounts.user.key(),
             input_token: "TOKEN".to_string(),
-            input_amount: token_amount,
-            output_amount: sol_amount,
-            fee: fee_amount,
+            input_amount: outcome.token_amount,
+            output_amount: outcome.sol_amount,
+            protocol_fee: outcome.protocol_fee,
+            creator_fee: outcome.creator_fee,
+            fee_recipient: ctx.accounts.fee_vault.key(),
         });
 
         Ok(())
     }
-}
 
-// ==================== HELPER FUNCTIONS ====================
+    /// Sell for an exact amount of SOL (after fees), spending at most `max_tokens_in`
+    pub fn sell_for_exact_sol(
+        ctx: Context<SwapTokensForSol>,
+        sol_amount: u64,
+        max_tokens_in: u64,
+    ) -> Result<()> {
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, PAUSE_SELLS)?;
 
-fn transfer_native_sol_fee(accounts: &MintMemeToken, fee_lamports: u64) -> Result<()> {
-    system_program::transfer(
-        CpiContext::new(
-            accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: accounts.minter.to_account_info(),
-                to: accounts.fee_vault.to_account_info(),
+        let fee_bps = ctx.accounts.pool.trade_fee_bps;
+        let creator_fee_bps = ctx.accounts.pool.creator_fee_bps;
+        let pool_key = ctx.accounts.pool.key();
+        let pool = &ctx.accounts.pool;
+
+        require!(sol_amount > 0, AmmError::InvalidAmount);
+        require!(pool.is_initialized, AmmError::PoolNotInitialized);
+        require!(!pool.is_complete, AmmError::CurveComplete);
+
+        let gross_sol = curve::gross_up_for_fees(sol_amount, fee_bps, creator_fee_bps)?;
+        require!(
+            gross_sol < pool.real_sol_reserve,
+            AmmError::InsufficientLiquidity
+        );
+
+        let outcome = quote_sell_exact_sol(pool, fee_bps, creator_fee_bps, sol_amount)?;
+        require!(outcome.token_amount <= max_tokens_in, AmmError::SlippageExceeded);
+
+        let sell_accounts = ctx.accounts.sell_accounts(ctx.bumps.sol_vault);
+        settle_sell(
+            &sell_accounts,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.creator_fee_vault,
+            &outcome,
+        )?;
+
+        emit!(SwapExecuted {
+            pool: pool_key,
+            user: ctx.accounts.user.key(),
+            input_token: "TOKEN".to_string(),
+            input_amount: outcome.token_amount,
+            output_amount: outcome.sol_amount,
+            protocol_fee: outcome.protocol_fee,
+            creator_fee: outcome.creator_fee,
+            fee_recipient: ctx.accounts.fee_vault.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Return the rent of a wallet's launch-window buy tracker once the
+    /// window is over
+    pub fn close_launch_buys(ctx: Context<CloseLaunchBuys>) -> Result<()> {
+        require!(
+            !ctx.accounts.pool.in_launch_window(Clock::get()?.slot),
+            AmmError::LaunchWindowActive
+        );
+        Ok(())
+    }
+
+    // ==================== QUOTES ====================
+
+    /// Read-only buy quote for `sol_amount` (fees included), returned as
+    /// Anchor return data. Call via simulateTransaction.
+    pub fn quote_buy(ctx: Context<QuotePool>, sol_amount: u64) -> Result<QuoteResult> {
+        let pool = &ctx.accounts.pool;
+        require_trading_open(&ctx.accounts.protocol_state, pool, PAUSE_BUYS)?;
+
+        require!(sol_amount > 0, AmmError::InvalidAmount);
+        require!(pool.is_initialized, AmmError::PoolNotInitialized);
+        require!(!pool.is_complete, AmmError::CurveComplete);
+
+        let outcome =
+            quote_buy_exact_sol(pool, pool.trade_fee_bps, pool.creator_fee_bps, sol_amount)?;
+        build_quote(pool, &outcome, true)
+    }
+
+    /// Read-only sell quote for `token_amount`, returned as Anchor return
+    /// data. Call via simulateTransaction.
+    pub fn quote_sell(ctx: Context<QuotePool>, token_amount: u64) -> Result<QuoteResult> {
+        let pool = &ctx.accounts.pool;
+        require_trading_open(&ctx.accounts.protocol_state, pool, PAUSE_SELLS)?;
+
+        require!(token_amount > 0, AmmError::InvalidAmount);
+        require!(pool.is_initialized, AmmError::PoolNotInitialized);
+        require!(!pool.is_complete, AmmError::CurveComplete);
+
+        let outcome =
+            quote_sell_exact_tokens(pool, pool.trade_fee_bps, pool.creator_fee_bps, token_amount)?;
+        build_quote(pool, &outcome, false)
+    }
+
+    // ==================== CREATOR FEES ====================
+
+    /// Original minter withdraws the creator share accrued from swaps
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        let creator_fee_vault = &mut ctx.accounts.creator_fee_vault;
+
+        let amount = creator_fee_vault
+            .total_accrued
+            .checked_sub(creator_fee_vault.total_claimed)
+            .ok_or(AmmError::MathOverflow)?;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        // Vault is program-owned, so lamports can be moved directly
+        **creator_fee_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.minter.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        creator_fee_vault.total_claimed = creator_fee_vault
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(AmmError::MathOverflow)?;
+
+        emit!(CreatorFeesClaimed {
+            token_mint: creator_fee_vault.token_mint,
+            creator: ctx.accounts.minter.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    // ==================== MIGRATION ====================
+
+    /// Move a graduated curve into a constant-product (x*y=k) pool
+    /// Seeds it with the curve's real SOL, the curve's leftover tokens and
+    /// the vault's liquidity reserve. LP tokens are minted into a
+    /// pool-owned lock account that no instruction can move.
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require_trading_open(&ctx.accounts.protocol_state, pool, PAUSE_MIGRATION)?;
+        require!(pool.is_complete, AmmError::CurveNotComplete);
+        require!(!pool.is_migrated, AmmError::AlreadyMigrated);
+
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let sol_amount = ctx.accounts.sol_vault.lamports();
+        let curve_tokens = ctx.accounts.token_vault.amount;
+        let reserve_tokens = pool.liquidity_reserve_tokens;
+        let token_amount = curve_tokens
+            .checked_add(reserve_tokens)
+            .ok_or(AmmError::MathOverflow)?;
+
+        require!(
+            sol_amount > 0 && token_amount > 0,
+            AmmError::InsufficientLiquidity
+        );
+
+        msg!(
+            "🚚 Migrating {} lamports + {} tokens ({} curve, {} vault reserve)",
+            sol_amount,
+            token_amount,
+            curve_tokens,
+            reserve_tokens
+        );
+
+        // SOL: curve vault → cp vault
+        let sol_vault_seeds: &[&[u8]] = &[
+            POOL_SOL_VAULT_SEED,
+            token_mint_key.as_ref(),
+            &[ctx.bumps.sol_vault],
+        ];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.cp_sol_vault.to_account_info(),
+                },
+                &[sol_vault_seeds],
+            ),
+            sol_amount,
+        )?;
+
+        // Tokens: curve vault → cp vault
+        if curve_tokens > 0 {
+            let pool_seeds: &[&[u8]] = &[AMM_POOL_SEED, token_mint_key.as_ref(), &[pool.bump]];
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_vault.to_account_info(),
+                        to: ctx.accounts.cp_token_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    &[pool_seeds],
+                ),
+                curve_tokens,
+                9,
+            )?;
+        }
+
+        // Tokens: minting vault liquidity reserve → cp vault
+        if reserve_tokens > 0 {
+            let vault_seeds: &[&[u8]] =
+                &[b"vault", token_mint_key.as_ref(), &[ctx.bumps.vault]];
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.vault_token_account.to_account_info(),
+                        to: ctx.accounts.cp_token_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        authority: ctx.accounts.vault.to_account_info(),
+                    },
+                    &[vault_seeds],
+                ),
+                reserve_tokens,
+                9,
+            )?;
+
+            emit!(ReserveDistributed {
+                token_mint: token_mint_key,
+                kind: ReserveKind::Liquidity,
+                recipient: ctx.accounts.cp_pool.key(),
+                amount: reserve_tokens,
+            });
+        }
+
+        // LP = sqrt(x * y), minted straight into the lock account
+        let lp_amount = integer_sqrt(
+            (sol_amount as u128)
+                .checked_mul(token_amount as u128)
+                .ok_or(AmmError::MathOverflow)?,
+        );
+        require!(lp_amount > 0, AmmError::InsufficientLiquidity);
+        let lp_amount = u64::try_from(lp_amount).map_err(|_| AmmError::MathOverflow)?;
+
+        let cp_pool_seeds: &[&[u8]] = &[
+            CP_POOL_SEED,
+            token_mint_key.as_ref(),
+            &[ctx.bumps.cp_pool],
+        ];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.lp_lock_account.to_account_info(),
+                    authority: ctx.accounts.cp_pool.to_account_info(),
+                },
+                &[cp_pool_seeds],
+            ),
+            lp_amount,
+        )?;
+
+        let cp_pool = &mut ctx.accounts.cp_pool;
+        cp_pool.token_mint = token_mint_key;
+        cp_pool.amm_pool = ctx.accounts.pool.key();
+        cp_pool.sol_vault = ctx.accounts.cp_sol_vault.key();
+        cp_pool.token_vault = ctx.accounts.cp_token_vault.key();
+        cp_pool.lp_mint = ctx.accounts.lp_mint.key();
+        cp_pool.sol_reserve = sol_amount;
+        cp_pool.token_reserve = token_amount;
+        cp_pool.lp_supply = lp_amount;
+        cp_pool.bump = ctx.bumps.cp_pool;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.real_sol_reserve = 0;
+        pool.real_token_reserve = 0;
+        pool.liquidity_reserve_tokens = 0;
+        pool.is_migrated = true;
+
+        msg!("✅ Pool migrated - {} LP locked", lp_amount);
+
+        emit!(PoolMigrated {
+            pool: pool.key(),
+            cp_pool: ctx.accounts.cp_pool.key(),
+            token_mint: token_mint_key,
+            sol_amount,
+            token_amount,
+            lp_amount,
+            lp_lock_account: ctx.accounts.lp_lock_account.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Trade against a migrated constant-product pool. `is_buy` swaps
+    /// `amount_in` lamports for tokens, otherwise `amount_in` tokens for SOL.
+    /// The fee is taken from the input and left in the pool.
+    pub fn cp_swap(
+        ctx: Context<CpSwap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        is_buy: bool,
+    ) -> Result<()> {
+        let pause_flag = if is_buy { PAUSE_BUYS } else { PAUSE_SELLS };
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, pause_flag)?;
+        require!(amount_in > 0, AmmError::InvalidAmount);
+
+        let cp_pool = &ctx.accounts.cp_pool;
+        let (fee, _) = curve::split_fees(amount_in, CP_SWAP_FEE_BPS, 0)?;
+        let amount_in_after_fee = amount_in
+            .checked_sub(fee)
+            .ok_or(AmmError::MathOverflow)?;
+
+        // ⭐ x * y = k on the pool's REAL reserves, rounded down for the user
+        let amount_out = if is_buy {
+            curve::quote_buy(cp_pool.sol_reserve, cp_pool.token_reserve, amount_in_after_fee)?
+        } else {
+            curve::quote_sell(cp_pool.sol_reserve, cp_pool.token_reserve, amount_in_after_fee)?
+        };
+        require!(amount_out > 0, AmmError::InvalidAmount);
+        require!(amount_out >= min_amount_out, AmmError::SlippageExceeded);
+
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let cp_pool_seeds: &[&[u8]] = &[CP_POOL_SEED, token_mint_key.as_ref(), &[cp_pool.bump]];
+
+        let (new_sol_reserve, new_token_reserve) = if is_buy {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.sol_vault.to_account_info(),
+                    },
+                ),
+                amount_in,
+            )?;
+
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_vault.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        authority: ctx.accounts.cp_pool.to_account_info(),
+                    },
+                    &[cp_pool_seeds],
+                ),
+                amount_out,
+                9,
+            )?;
+
+            (
+                cp_pool.sol_reserve.checked_add(amount_in),
+                cp_pool.token_reserve.checked_sub(amount_out),
+            )
+        } else {
+            token::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        to: ctx.accounts.token_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                amount_in,
+                9,
+            )?;
+
+            // The SOL vault must stay rent-exempt
+            let new_sol_reserve = cp_pool.sol_reserve.checked_sub(amount_out);
+            let rent_exempt_min = Rent::get()?.minimum_balance(0);
+            require!(
+                new_sol_reserve.is_some_and(|reserve| reserve >= rent_exempt_min),
+                AmmError::InsufficientLiquidity
+            );
+
+            let sol_vault_seeds: &[&[u8]] = &[
+                CP_SOL_VAULT_SEED,
+                token_mint_key.as_ref(),
+                &[ctx.bumps.sol_vault],
+            ];
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.sol_vault.to_account_info(),
+                        to: ctx.accounts.user.to_account_info(),
+                    },
+                    &[sol_vault_seeds],
+                ),
+                amount_out,
+            )?;
+
+            (new_sol_reserve, cp_pool.token_reserve.checked_add(amount_in))
+        };
+
+        let cp_pool = &mut ctx.accounts.cp_pool;
+        cp_pool.sol_reserve = new_sol_reserve.ok_or(AmmError::MathOverflow)?;
+        cp_pool.token_reserve = new_token_reserve.ok_or(AmmError::MathOverflow)?;
+
+        msg!(
+            "💱 CP swap {} in → {} out (fee {}), reserves {} SOL / {} tokens",
+            amount_in,
+            amount_out,
+            fee,
+            cp_pool.sol_reserve,
+            cp_pool.token_reserve
+        );
+
+        emit!(CpSwapExecuted {
+            cp_pool: cp_pool.key(),
+            user: ctx.accounts.user.key(),
+            is_buy,
+            amount_in,
+            amount_out,
+            fee,
+            sol_reserve: cp_pool.sol_reserve,
+            token_reserve: cp_pool.token_reserve,
+        });
+
+        Ok(())
+    }
+
+    // ==================== RESERVE ALLOCATION ====================
+
+    /// Burn the vault reserve's burn allocation (anyone can call)
+    pub fn burn_reserve(ctx: Context<BurnReserve>) -> Result<()> {
+        let amount = ctx.accounts.pool.burn_reserve_tokens;
+        require!(amount > 0, ErrorCode::NothingToDistribute);
+
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let vault_seeds: &[&[u8]] = &[b"vault", token_mint_key.as_ref(), &[ctx.bumps.vault]];
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.burn_reserve_tokens = 0;
+        ctx.accounts.meme_token_state.record_burn(amount)?;
+
+        msg!("🔥 Burned {} reserve tokens", amount);
+
+        emit!(ReserveDistributed {
+            token_mint: token_mint_key,
+            kind: ReserveKind::Burn,
+            recipient: Pubkey::default(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Move the creator allocation from the minting vault into the mint's
+    /// vesting escrow (anyone can call). Vesting runs from mint time.
+    pub fn fund_vesting(ctx: Context<FundVesting>) -> Result<()> {
+        let amount = ctx.accounts.pool.creator_reserve_tokens;
+        require!(amount > 0, ErrorCode::NothingToDistribute);
+
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let vault_seeds: &[&[u8]] = &[b"vault", token_mint_key.as_ref(), &[ctx.bumps.vault]];
+
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.vesting_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+            9,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.creator_reserve_tokens = 0;
+
+        let start_ts = ctx.accounts.meme_token_state.created_at;
+        let vesting = &mut ctx.accounts.vesting;
+        vesting.token_mint = token_mint_key;
+        vesting.beneficiary = ctx.accounts.meme_token_state.minter;
+        vesting.total_amount = amount;
+        vesting.claimed_amount = 0;
+        vesting.start_ts = start_ts;
+        vesting.cliff_ts = start_ts.saturating_add(pool.vesting_cliff_seconds);
+        vesting.end_ts = start_ts.saturating_add(pool.vesting_duration_seconds);
+        vesting.bump = ctx.bumps.vesting;
+        vesting.token_account_bump = ctx.bumps.vesting_token_account;
+
+        emit!(ReserveDistributed {
+            token_mint: token_mint_key,
+            kind: ReserveKind::Creator,
+            recipient: vesting.key(),
+            amount,
+        });
+        emit!(VestingFunded {
+            token_mint: token_mint_key,
+            beneficiary: vesting.beneficiary,
+            amount,
+            start_ts: vesting.start_ts,
+            cliff_ts: vesting.cliff_ts,
+            end_ts: vesting.end_ts,
+        });
+
+        Ok(())
+    }
+
+    /// Minter claims whatever has unlocked so far
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let vesting = &ctx.accounts.vesting;
+        let amount = vesting
+            .vested_amount(now)
+            .checked_sub(vesting.claimed_amount)
+            .ok_or(AmmError::MathOverflow)?;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let vesting_seeds: &[&[u8]] = &[VESTING_SEED, token_mint_key.as_ref(), &[vesting.bump]];
+
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vesting_token_account.to_account_info(),
+                    to: ctx.accounts.minter_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    authority: ctx.accounts.vesting.to_account_info(),
+                },
+                &[vesting_seeds],
+            ),
+            amount,
+            9,
+        )?;
+
+        let vesting = &mut ctx.accounts.vesting;
+        vesting.claimed_amount = vesting
+            .claimed_amount
+            .checked_add(amount)
+            .ok_or(AmmError::MathOverflow)?;
+        let remaining = vesting
+            .total_amount
+            .checked_sub(vesting.claimed_amount)
+            .ok_or(AmmError::MathOverflow)?;
+
+        emit!(VestedClaimed {
+            token_mint: token_mint_key,
+            minter: ctx.accounts.minter.key(),
+            amount,
+            total_claimed: vesting.claimed_amount,
+            remaining,
+        });
+
+        Ok(())
+    }
+
+    /// Read-only view of the vault reserve for a mint, returned as Anchor
+    /// return data. Call via simulateTransaction.
+    pub fn reserve_status(ctx: Context<ReserveStatusView>) -> Result<ReserveStatus> {
+        let pool = &ctx.accounts.pool;
+        let vault_balance = ctx.accounts.vault_token_account.amount;
+        let allocated = pool
+            .liquidity_reserve_tokens
+            .saturating_add(pool.creator_reserve_tokens)
+            .saturating_add(pool.burn_reserve_tokens);
+
+        Ok(ReserveStatus {
+            vault_balance,
+            liquidity_reserve_tokens: pool.liquidity_reserve_tokens,
+            creator_reserve_tokens: pool.creator_reserve_tokens,
+            burn_reserve_tokens: pool.burn_reserve_tokens,
+            unallocated: vault_balance.saturating_sub(allocated),
+        })
+    }
+
+    // ==================== BURNS ====================
+
+    /// Burn tokens from the caller's own account (any holder)
+    pub fn burn_meme_tokens(ctx: Context<BurnMemeTokens>, amount: u64) -> Result<()> {
+        require!(amount > 0, AmmError::InvalidAmount);
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let total_burned = ctx.accounts.meme_token_state.record_burn(amount)?;
+
+        msg!("🔥 Burned {} tokens (total burned: {})", amount, total_burned);
+
+        emit!(TokensBurned {
+            token_mint: ctx.accounts.token_mint.key(),
+            holder: ctx.accounts.holder.key(),
+            amount,
+            total_burned,
+        });
+
+        Ok(())
+    }
+
+    /// Spend fee vault lamports buying on the curve and burn what they buy
+    /// (authority only). The buy is fee-free: the protocol fee would only
+    /// flow back into the fee vault, and creators are not paid on buybacks.
+    pub fn buyback_and_burn(
+        ctx: Context<BuybackAndBurn>,
+        sol_amount: u64,
+        min_token_amount: u64,
+    ) -> Result<()> {
+        require_trading_open(&ctx.accounts.protocol_state, &ctx.accounts.pool, PAUSE_BUYS)?;
+        require!(sol_amount > 0, AmmError::InvalidAmount);
+
+        let pool_key = ctx.accounts.pool.key();
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.is_initialized, AmmError::PoolNotInitialized);
+        require!(!pool.is_complete, AmmError::CurveComplete);
+
+        let rent_exempt_min = Rent::get()?.minimum_balance(0);
+        let available = ctx
+            .accounts
+            .fee_vault
+            .lamports()
+            .saturating_sub(rent_exempt_min);
+        require!(sol_amount <= available, ErrorCode::InsufficientFeeVaultBalance);
+
+        // ⭐ Same curve pricing as swap_sol_to_tokens, minus the fees
+        let outcome = quote_buy_exact_sol(pool, 0, 0, sol_amount)?;
+        require!(
+            outcome.token_amount >= min_token_amount,
+            AmmError::SlippageExceeded
+        );
+
+        let fee_vault_seeds: &[&[u8]] = &[b"fee_vault", &[ctx.bumps.fee_vault]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.fee_vault.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                },
+                &[fee_vault_seeds],
+            ),
+            outcome.sol_amount,
+        )?;
+
+        // Burn straight out of the pool's token vault
+        let token_mint_key = pool.token_mint;
+        let pool_seeds: &[&[u8]] = &[AMM_POOL_SEED, token_mint_key.as_ref(), &[pool.bump]];
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            outcome.token_amount,
+        )?;
+
+        apply_buy_reserves(pool, &outcome)?;
+        pool.record_trade(outcome.sol_amount, true)?;
+
+        let total_burned = ctx.accounts.meme_token_state.record_burn(outcome.token_amount)?;
+
+        msg!(
+            "🔥 Buyback burned {} tokens for {} lamports",
+            outcome.token_amount,
+            outcome.sol_amount
+        );
+
+        emit!(BuybackBurned {
+            pool: pool_key,
+            token_mint: token_mint_key,
+            sol_amount: outcome.sol_amount,
+            token_amount: outcome.token_amount,
+            total_burned,
+        });
+
+        check_graduation(&mut ctx.accounts.pool, pool_key);
+
+        Ok(())
+    }
+
+    // ==================== LEGACY ACCOUNT UPGRADES ====================
+
+    /// Grow a MemeTokenState minted under the original layout (anyone can
+    /// call, payer covers the extra rent). New fields start zeroed: no pool
+    /// recorded yet, nothing burned.
+    pub fn upgrade_meme_token_state(ctx: Context<UpgradeMemeTokenState>) -> Result<()> {
+        grow_legacy_account(
+            &ctx.accounts.meme_token_state,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            MemeTokenState::DISCRIMINATOR,
+            MemeTokenState::LEGACY_LEN,
+            MemeTokenState::LEN,
+        )?;
+
+        msg!("⬆️ Upgraded meme token state {}", ctx.accounts.meme_token_state.key());
+
+        Ok(())
+    }
+
+    /// Grow an AmmPool created under the original layout and fill in the new
+    /// fields (authority only). Reserves are kept as-is; pricing stays
+    /// constant-product and fees / graduation come from the default launch
+    /// config. Legacy pools have no launch window, and the tokens left in
+    /// their minting vault all become the liquidity reserve.
+    pub fn upgrade_amm_pool(ctx: Context<UpgradeAmmPool>) -> Result<()> {
+        grow_legacy_account(
+            &ctx.accounts.pool,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            AmmPool::DISCRIMINATOR,
+            AmmPool::LEGACY_LEN,
+            AmmPool::LEN,
+        )?;
+
+        let pool_key = ctx.accounts.pool.key();
+        let launch_config = &ctx.accounts.launch_config;
+        {
+            let pool_info = ctx.accounts.pool.to_account_info();
+            let mut data = pool_info.try_borrow_mut_data()?;
+            let mut pool = AmmPool::try_deserialize(&mut &data[..])?;
+            require!(
+                pool.token_mint == ctx.accounts.token_mint.key(),
+                AmmError::InvalidMint
+            );
+
+            // Every legacy pool launched with FIXED_INITIAL_TOKENS on the
+            // curve, whatever the default config says today
+            pool.launch_config = launch_config.key();
+            pool.curve_tokens = FIXED_INITIAL_TOKENS;
+            pool.trade_fee_bps = launch_config.trade_fee_bps;
+            pool.creator_fee_bps = launch_config.creator_fee_bps;
+            pool.graduation_sol_threshold = launch_config.graduation_sol_threshold;
+            pool.curve_type = CurveType::ConstantProduct;
+            pool.liquidity_reserve_tokens = ctx.accounts.vault_token_account.amount;
+            pool.refresh_market_data()?;
+
+            pool.try_serialize(&mut &mut data[..])?;
+        }
+
+        init_creator_fee_vault(
+            &mut ctx.accounts.creator_fee_vault,
+            ctx.accounts.token_mint.key(),
+            ctx.bumps.creator_fee_vault,
+        );
+        ctx.accounts.meme_token_state.pool = pool_key;
+
+        msg!(
+            "⬆️ Upgraded pool {} with launch config #{}",
+            pool_key,
+            launch_config.config_id
+        );
+
+        Ok(())
+    }
+}
+
+// ==================== CURVE MATH ====================
+
+// Pure pricing for the pool's price curves. No account access, every
+// operation is checked and returns AmmError::MathOverflow instead of
+// panicking.
+//
+// Rounding always favours the pool: amounts paid out are rounded down,
+// amounts owed to the pool are rounded up, so virtual_sol * virtual_tokens
+// never decreases across a trade and the linear / exponential curves never
+// pay out more than was paid in. Fees are rounded down.
+pub mod curve {
+    use super::{AmmError, BPS_DENOMINATOR};
+    use anchor_lang::prelude::*;
+
+    /// Fixed-point scale for prices and exp/ln
+    pub const WAD: u128 = 1_000_000_000_000_000_000;
+    const LN2_WAD: u128 = 693_147_180_559_945_309;
+
+    /// Tokens out for `sol_in` (post-fee) SOL. Rounds down.
+    pub fn quote_buy(virtual_sol: u64, virtual_tokens: u64, sol_in: u64) -> Result<u64> {
+        let new_virtual_sol = (virtual_sol as u128)
+            .checked_add(sol_in as u128)
+            .ok_or(AmmError::MathOverflow)?;
+        mul_div_floor(sol_in as u128, virtual_tokens as u128, new_virtual_sol)
+    }
+
+    /// Post-fee SOL needed to buy exactly `tokens_out`. Rounds up.
+    pub fn quote_buy_exact_out(
+        virtual_sol: u64,
+        virtual_tokens: u64,
+        tokens_out: u64,
+    ) -> Result<u64> {
+        let new_virtual_tokens = (virtual_tokens as u128)
+            .checked_sub(tokens_out as u128)
+            .ok_or(AmmError::InsufficientLiquidity)?;
+        require!(new_virtual_tokens > 0, AmmError::InsufficientLiquidity);
+        mul_div_ceil(tokens_out as u128, virtual_sol as u128, new_virtual_tokens)
+    }
+
+    /// SOL out (pre-fee) for selling `tokens_in`. Rounds down.
+    pub fn quote_sell(virtual_sol: u64, virtual_tokens: u64, tokens_in: u64) -> Result<u64> {
+        let new_virtual_tokens = (virtual_tokens as u128)
+            .checked_add(tokens_in as u128)
+            .ok_or(AmmError::MathOverflow)?;
+        mul_div_floor(tokens_in as u128, virtual_sol as u128, new_virtual_tokens)
+    }
+
+    /// Tokens needed to take exactly `sol_out` (pre-fee) out of the curve.
+    /// Rounds up.
+    pub fn quote_sell_exact_out(
+        virtual_sol: u64,
+        virtual_tokens: u64,
+        sol_out: u64,
+    ) -> Result<u64> {
+        let new_virtual_sol = (virtual_sol as u128)
+            .checked_sub(sol_out as u128)
+            .ok_or(AmmError::InsufficientLiquidity)?;
+        require!(new_virtual_sol > 0, AmmError::InsufficientLiquidity);
+        mul_div_ceil(sol_out as u128, virtual_tokens as u128, new_virtual_sol)
+    }
+
+    /// (protocol fee, creator fee) on `gross`. Both round down.
+    pub fn split_fees(gross: u64, fee_bps: u16, creator_fee_bps: u16) -> Result<(u64, u64)> {
+        let fee = mul_div_floor(gross as u128, fee_bps as u128, BPS_DENOMINATOR as u128)?;
+        let creator_fee =
+            mul_div_floor(gross as u128, creator_fee_bps as u128, BPS_DENOMINATOR as u128)?;
+        Ok((fee, creator_fee))
+    }
+
+    /// Smallest gross amount whose post-fee part covers `net`. Rounds up.
+    pub fn gross_up_for_fees(net: u64, fee_bps: u16, creator_fee_bps: u16) -> Result<u64> {
+        let keep = (BPS_DENOMINATOR as u128)
+            .checked_sub(fee_bps as u128)
+            .and_then(|keep| keep.checked_sub(creator_fee_bps as u128))
+            .ok_or(AmmError::MathOverflow)?;
+        require!(keep > 0, AmmError::MathOverflow);
+        mul_div_ceil(net as u128, BPS_DENOMINATOR as u128, keep)
+    }
+
+    /// Common interface for the pool's price curves. Amounts are in base
+    /// units / lamports and SOL is always post-fee (buys) or pre-fee (sells).
+    pub trait BondingCurve {
+        /// Tokens out for `sol_in`. Rounds down.
+        fn buy(&self, sol_in: u64) -> Result<u64>;
+        /// SOL needed to buy exactly `tokens_out`. Rounds up.
+        fn buy_exact_out(&self, tokens_out: u64) -> Result<u64>;
+        /// SOL out for selling `tokens_in`. Rounds down.
+        fn sell(&self, tokens_in: u64) -> Result<u64>;
+        /// Tokens needed to take exactly `sol_out` out of the curve. Rounds up.
+        fn sell_exact_out(&self, sol_out: u64) -> Result<u64>;
+        /// Marginal price in lamports per token base unit, scaled by WAD.
+        fn spot_price(&self) -> Result<u128>;
+    }
+
+    /// x * y = k on virtual reserves (pump.fun style)
+    pub struct ConstantProduct {
+        pub virtual_sol: u64,
+        pub virtual_tokens: u64,
+    }
+
+    impl BondingCurve for ConstantProduct {
+        fn buy(&self, sol_in: u64) -> Result<u64> {
+            quote_buy(self.virtual_sol, self.virtual_tokens, sol_in)
+        }
+
+        fn buy_exact_out(&self, tokens_out: u64) -> Result<u64> {
+            quote_buy_exact_out(self.virtual_sol, self.virtual_tokens, tokens_out)
+        }
+
+        fn sell(&self, tokens_in: u64) -> Result<u64> {
+            quote_sell(self.virtual_sol, self.virtual_tokens, tokens_in)
+        }
+
+        fn sell_exact_out(&self, sol_out: u64) -> Result<u64> {
+            quote_sell_exact_out(self.virtual_sol, self.virtual_tokens, sol_out)
+        }
+
+        fn spot_price(&self) -> Result<u128> {
+            (self.virtual_sol as u128)
+                .checked_mul(WAD)
+                .and_then(|scaled| scaled.checked_div(self.virtual_tokens as u128))
+                .ok_or_else(|| error!(AmmError::MathOverflow))
+        }
+    }
+
+    /// price(s) = start_price + slope * s / WAD
+    ///
+    /// Prices are lamports per token base unit scaled by WAD; `slope` is the
+    /// price increase per 1e18 base units (1B tokens) sold.
+    pub struct Linear {
+        pub start_price: u64,
+        pub slope: u64,
+        pub tokens_sold: u64,
+    }
+
+    impl Linear {
+        fn price(&self, tokens_sold: u64) -> Result<u128> {
+            (self.slope as u128)
+                .checked_mul(tokens_sold as u128)
+                .map(|ramp| ramp / WAD)
+                .and_then(|ramp| ramp.checked_add(self.start_price as u128))
+                .ok_or_else(|| error!(AmmError::MathOverflow))
+        }
+
+        // Area under the price line between `from` and `to` (from <= to)
+        fn cost(&self, from: u64, to: u64, round_up: bool) -> Result<u64> {
+            let amount = (to - from) as u128;
+            let base = amount
+                .checked_mul(self.start_price as u128)
+                .ok_or(AmmError::MathOverflow)?;
+            let span = amount
+                .checked_mul((from as u128) + (to as u128))
+                .ok_or(AmmError::MathOverflow)?;
+            let span = div_round(span, 2 * WAD, round_up)?;
+            let ramp = span
+                .checked_mul(self.slope as u128)
+                .ok_or(AmmError::MathOverflow)?;
+            let total = base.checked_add(ramp).ok_or(AmmError::MathOverflow)?;
+            to_u64(div_round(total, WAD, round_up)?)
+        }
+
+        // Inverse of the price line: tokens sold at which price reaches `price`
+        fn tokens_sold_at(&self, price: u128) -> Result<u64> {
+            let ramp = price.saturating_sub(self.start_price as u128);
+            mul_div_floor(ramp, WAD, self.slope as u128)
+        }
+    }
+
+    impl BondingCurve for Linear {
+        // cost = (p1² - p0²) / (2 * slope)  =>  p1 = sqrt(p0² + 2 * slope * sol)
+        fn buy(&self, sol_in: u64) -> Result<u64> {
+            let price = self.price(self.tokens_sold)?;
+            let price_squared = price
+                .checked_mul(price)
+                .and_then(|squared| {
+                    (self.slope as u128)
+                        .checked_mul(2 * sol_in as u128)
+                        .and_then(|area| squared.checked_add(area))
+                })
+                .ok_or(AmmError::MathOverflow)?;
+            let new_tokens_sold = self.tokens_sold_at(super::integer_sqrt(price_squared))?;
+            Ok(new_tokens_sold.saturating_sub(self.tokens_sold))
+        }
+
+        fn buy_exact_out(&self, tokens_out: u64) -> Result<u64> {
+            let new_tokens_sold = self
+                .tokens_sold
+                .checked_add(tokens_out)
+                .ok_or(AmmError::MathOverflow)?;
+            self.cost(self.tokens_sold, new_tokens_sold, true)
+        }
+
+        fn sell(&self, tokens_in: u64) -> Result<u64> {
+            let new_tokens_sold = self
+                .tokens_sold
+                .checked_sub(tokens_in)
+                .ok_or(AmmError::InsufficientLiquidity)?;
+            self.cost(new_tokens_sold, self.tokens_sold, false)
+        }
+
+        fn sell_exact_out(&self, sol_out: u64) -> Result<u64> {
+            let price = self.price(self.tokens_sold)?;
+            let area = (self.slope as u128)
+                .checked_mul(2 * sol_out as u128)
+                .ok_or(AmmError::MathOverflow)?;
+            let price_squared = price
+                .checked_mul(price)
+                .ok_or(AmmError::MathOverflow)?
+                .checked_sub(area)
+                .ok_or(AmmError::InsufficientLiquidity)?;
+            let new_tokens_sold = self.tokens_sold_at(super::integer_sqrt(price_squared))?;
+            Ok(self.tokens_sold - new_tokens_sold.min(self.tokens_sold))
+        }
+
+        fn spot_price(&self) -> Result<u128> {
+            self.price(self.tokens_sold)
+        }
+    }
+
+    /// price(s) = start_price * e^(growth * s / WAD)
+    ///
+    /// `start_price` uses the same scale as [`Linear`]; `growth` is the
+    /// exponent (WAD-scaled) reached after 1e18 base units (1B tokens) sold.
+    pub struct Exponential {
+        pub start_price: u64,
+        pub growth: u64,
+        pub tokens_sold: u64,
+    }
+
+    impl Exponential {
+        fn exponent(&self, tokens_sold: u64, round_up: bool) -> Result<u128> {
+            let exponent = (self.growth as u128)
+                .checked_mul(tokens_sold as u128)
+                .ok_or(AmmError::MathOverflow)?;
+            div_round(exponent, WAD, round_up)
+        }
+
+        // cost = start_price * (e^x1 - e^x0) / growth
+        fn cost(&self, from: u64, to: u64, round_up: bool) -> Result<u64> {
+            let (low, high) = if round_up {
+                (
+                    exp_wad(self.exponent(from, false)?)?,
+                    exp_wad(self.exponent(to, true)?)?
+                        .checked_add(1)
+                        .ok_or(AmmError::MathOverflow)?,
+                )
+            } else {
+                (
+                    exp_wad(self.exponent(from, true)?)?,
+                    exp_wad(self.exponent(to, false)?)?,
+                )
+            };
+            let delta = high.saturating_sub(low);
+            let total = delta
+                .checked_mul(self.start_price as u128)
+                .ok_or(AmmError::MathOverflow)?;
+            to_u64(div_round(total, self.growth as u128, round_up)?)
+        }
+
+        // Inverse of e^x: tokens sold at which e^x reaches `scaled_exp`
+        fn tokens_sold_at(&self, scaled_exp: u128) -> Result<u64> {
+            mul_div_floor(ln_wad(scaled_exp)?, WAD, self.growth as u128)
+        }
+    }
+
+    impl BondingCurve for Exponential {
+        fn buy(&self, sol_in: u64) -> Result<u64> {
+            let current = exp_wad(self.exponent(self.tokens_sold, false)?)?;
+            let added = (sol_in as u128)
+                .checked_mul(self.growth as u128)
+                .and_then(|scaled| scaled.checked_div(self.start_price as u128))
+                .ok_or(AmmError::MathOverflow)?;
+            let target = current.checked_add(added).ok_or(AmmError::MathOverflow)?;
+            let new_tokens_sold = self.tokens_sold_at(target)?;
+            Ok(new_tokens_sold.saturating_sub(self.tokens_sold))
+        }
+
+        fn buy_exact_out(&self, tokens_out: u64) -> Result<u64> {
+            let new_tokens_sold = self
+                .tokens_sold
+                .checked_add(tokens_out)
+                .ok_or(AmmError::MathOverflow)?;
+            self.cost(self.tokens_sold, new_tokens_sold, true)
+        }
+
+        fn sell(&self, tokens_in: u64) -> Result<u64> {
+            let new_tokens_sold = self
+                .tokens_sold
+                .checked_sub(tokens_in)
+                .ok_or(AmmError::InsufficientLiquidity)?;
+            self.cost(new_tokens_sold, self.tokens_sold, false)
+        }
+
+        fn sell_exact_out(&self, sol_out: u64) -> Result<u64> {
+            let current = exp_wad(self.exponent(self.tokens_sold, false)?)?;
+            let removed = div_round(
+                (sol_out as u128)
+                    .checked_mul(self.growth as u128)
+                    .ok_or(AmmError::MathOverflow)?,
+                self.start_price as u128,
+                true,
+            )?;
+            let target = current
+                .checked_sub(removed)
+                .filter(|target| *target >= WAD)
+                .ok_or(AmmError::InsufficientLiquidity)?;
+            let new_tokens_sold = self.tokens_sold_at(target)?;
+            Ok(self.tokens_sold - new_tokens_sold.min(self.tokens_sold))
+        }
+
+        fn spot_price(&self) -> Result<u128> {
+            let scaled_exp = exp_wad(self.exponent(self.tokens_sold, false)?)?;
+            scaled_exp
+                .checked_mul(self.start_price as u128)
+                .map(|price| price / WAD)
+                .ok_or_else(|| error!(AmmError::MathOverflow))
+        }
+    }
+
+    /// e^x for a WAD-scaled x >= 0, WAD-scaled. Rounds down.
+    pub fn exp_wad(x: u128) -> Result<u128> {
+        // e^x = 2^n * e^r with r in [0, ln 2)
+        let n = x / LN2_WAD;
+        let r = x % LN2_WAD;
+
+        let mut term = WAD;
+        let mut sum = WAD;
+        let mut i = 1u128;
+        while term > 0 {
+            term = term * r / WAD / i;
+            sum += term;
+            i += 1;
+        }
+
+        require!(n < sum.leading_zeros() as u128, AmmError::MathOverflow);
+        Ok(sum << n)
+    }
+
+    /// ln(y) for a WAD-scaled y >= 1, WAD-scaled. Rounds down.
+    pub fn ln_wad(y: u128) -> Result<u128> {
+        require!(y >= WAD, AmmError::MathOverflow);
+
+        // ln(y) = n * ln 2 + ln(z) with z in [1, 2)
+        let mut n = 0u128;
+        let mut z = y;
+        while z >= 2 * WAD {
+            z >>= 1;
+            n += 1;
+        }
+
+        // ln(z) = 2 * atanh(t), t = (z - 1) / (z + 1) <= 1/3
+        let t = (z - WAD) * WAD / (z + WAD);
+        let t_squared = t * t / WAD;
+        let mut term = t;
+        let mut sum = 0u128;
+        let mut k = 1u128;
+        while term > 0 {
+            sum += term / k;
+            term = term * t_squared / WAD;
+            k += 2;
+        }
+
+        Ok(n * LN2_WAD + 2 * sum)
+    }
+
+    fn div_round(value: u128, denominator: u128, round_up: bool) -> Result<u128> {
+        require!(denominator > 0, AmmError::MathOverflow);
+        if round_up {
+            value
+                .checked_add(denominator - 1)
+                .map(|rounded| rounded / denominator)
+                .ok_or_else(|| error!(AmmError::MathOverflow))
+        } else {
+            Ok(value / denominator)
+        }
+    }
+
+    fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Result<u64> {
+        let value = a
+            .checked_mul(b)
+            .and_then(|product| product.checked_div(denominator))
+            .ok_or(AmmError::MathOverflow)?;
+        to_u64(value)
+    }
+
+    fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Result<u64> {
+        require!(denominator > 0, AmmError::MathOverflow);
+        let value = a
+            .checked_mul(b)
+            .and_then(|product| product.checked_add(denominator - 1))
+            .and_then(|rounded| rounded.checked_div(denominator))
+            .ok_or(AmmError::MathOverflow)?;
+        to_u64(value)
+    }
+
+    fn to_u64(value: u128) -> Result<u64> {
+        u64::try_from(value).map_err(|_| error!(AmmError::MathOverflow))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const CASES: usize = 2_000;
+        const CURVE_TOKENS: u64 = 800_000_000_000_000_000;
+
+        // xorshift64*, deterministic so failures reproduce
+        struct Rng(u64);
+
+        impl Rng {
+            fn next(&mut self) -> u64 {
+                self.0 ^= self.0 >> 12;
+                self.0 ^= self.0 << 25;
+                self.0 ^= self.0 >> 27;
+                self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+            }
+
+            fn range(&mut self, low: u64, high: u64) -> u64 {
+                low + self.next() % (high - low)
+            }
+        }
+
+        fn random_pool(rng: &mut Rng) -> ConstantProduct {
+            ConstantProduct {
+                virtual_sol: rng.range(1_000_000_000, 1_000_000_000_000),
+                virtual_tokens: rng.range(100_000_000_000_000_000, 1_100_000_000_000_000_000),
+            }
+        }
+
+        fn k(virtual_sol: u64, virtual_tokens: u64) -> u128 {
+            virtual_sol as u128 * virtual_tokens as u128
+        }
+
+        fn linear(tokens_sold: u64) -> Linear {
+            Linear {
+                start_price: 28_000_000_000,
+                slope: 500_000_000_000,
+                tokens_sold,
+            }
+        }
+
+        fn exponential(tokens_sold: u64) -> Exponential {
+            Exponential {
+                start_price: 28_000_000_000,
+                growth: 3_400_000_000_000_000_000,
+                tokens_sold,
+            }
+        }
+
+        #[test]
+        fn constant_product_buy_never_decreases_k() {
+            let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+            for _ in 0..CASES {
+                let pool = random_pool(&mut rng);
+                let sol_in = rng.range(1, pool.virtual_sol * 10);
+                let tokens_out = pool.buy(sol_in).unwrap();
+                assert!(tokens_out < pool.virtual_tokens);
+                assert!(
+                    k(pool.virtual_sol + sol_in, pool.virtual_tokens - tokens_out)
+                        >= k(pool.virtual_sol, pool.virtual_tokens)
+                );
+            }
+        }
+
+        #[test]
+        fn constant_product_buy_exact_out_never_decreases_k() {
+            let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+            for _ in 0..CASES {
+                let pool = random_pool(&mut rng);
+                let tokens_out = rng.range(1, pool.virtual_tokens / 2);
+                let sol_in = pool.buy_exact_out(tokens_out).unwrap();
+                assert!(
+                    k(pool.virtual_sol + sol_in, pool.virtual_tokens - tokens_out)
+                        >= k(pool.virtual_sol, pool.virtual_tokens)
+                );
+            }
+        }
+
+        #[test]
+        fn constant_product_sell_never_decreases_k() {
+            let mut rng = Rng(0x8cb9_2ba7_2f3d_8dd7);
+            for _ in 0..CASES {
+                let pool = random_pool(&mut rng);
+                let tokens_in = rng.range(1, pool.virtual_tokens);
+                let sol_out = pool.sell(tokens_in).unwrap();
+                assert!(sol_out < pool.virtual_sol);
+                assert!(
+                    k(pool.virtual_sol - sol_out, pool.virtual_tokens + tokens_in)
+                        >= k(pool.virtual_sol, pool.virtual_tokens)
+                );
+            }
+        }
+
+        #[test]
+        fn constant_product_sell_exact_out_never_decreases_k() {
+            let mut rng = Rng(0xa076_1d64_78bd_642f);
+            for _ in 0..CASES {
+                let pool = random_pool(&mut rng);
+                let sol_out = rng.range(1, pool.virtual_sol / 2);
+                let tokens_in = pool.sell_exact_out(sol_out).unwrap();
+                assert!(
+                    k(pool.virtual_sol - sol_out, pool.virtual_tokens + tokens_in)
+                        >= k(pool.virtual_sol, pool.virtual_tokens)
+                );
+            }
+        }
+
+        // Buying and selling straight back, or buying in steps and selling
+        // everything, never pays out more than went in; exact-out sells
+        // always take enough tokens
+        fn assert_round_trips_solvent(curve: fn(u64) -> Box<dyn BondingCurve>, seed: u64) {
+            let mut rng = Rng(seed);
+            for _ in 0..CASES / 10 {
+                let mut tokens_sold = 0u64;
+                let mut sol_paid = 0u64;
+                for _ in 0..rng.range(1, 6) {
+                    let tokens_out = rng.range(1, CURVE_TOKENS / 8);
+                    let sol_in = curve(tokens_sold).buy_exact_out(tokens_out).unwrap();
+                    let tokens_for_sol = curve(tokens_sold).buy(sol_in).unwrap();
+                    let sol_back = curve(tokens_sold + tokens_for_sol)
+                        .sell(tokens_for_sol)
+                        .unwrap();
+                    assert!(sol_back <= sol_in);
+                    tokens_sold += tokens_out;
+                    sol_paid += sol_in;
+                }
+
+                let sol_out = curve(tokens_sold).sell(tokens_sold).unwrap();
+                assert!(sol_out <= sol_paid);
+
+                let wanted = rng.range(1, sol_out.max(2));
+                let tokens_in = curve(tokens_sold).sell_exact_out(wanted).unwrap();
+                assert!(curve(tokens_sold).sell(tokens_in).unwrap() >= wanted);
+            }
+        }
+
+        #[test]
+        fn linear_round_trips_are_solvent() {
+            assert_round_trips_solvent(|sold| Box::new(linear(sold)), 0xe703_7ed1_a0b4_28db);
+        }
+
+        #[test]
+        fn exponential_round_trips_are_solvent() {
+            assert_round_trips_solvent(|sold| Box::new(exponential(sold)), 0x5851_f42d_4c95_7f2d);
+        }
+
+        #[test]
+        fn exp_and_ln_are_inverse() {
+            for x in [0, WAD / 2, WAD, 3 * WAD, 10 * WAD] {
+                let round_trip = ln_wad(exp_wad(x).unwrap()).unwrap();
+                assert!(round_trip.abs_diff(x) <= 1_000);
+            }
+        }
+    }
+}
+
+// ==================== HELPER FUNCTIONS ====================
+
+// AccountInfos used by the mint flow (mint_meme_token and launch_meme)
+struct MintAccounts<'info> {
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    vault_token_account: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    vault_bump: u8,
+}
+
+// AccountInfos used to fund a new pool (initialize_amm_pool and launch_meme)
+struct PoolSeedAccounts<'info> {
+    payer: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    sol_vault: AccountInfo<'info>,
+    token_vault: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    vault_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    vault_bump: u8,
+}
+
+// AccountInfos used by a curve buy (swap_sol_to_tokens and launch_meme)
+struct BuyAccounts<'info> {
+    user: AccountInfo<'info>,
+    pool: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    sol_vault: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+    creator_fee_vault: AccountInfo<'info>,
+    token_vault: AccountInfo<'info>,
+    user_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+// AccountInfos used by a curve sell (swap_tokens_to_sol and sell_for_exact_sol)
+struct SellAccounts<'info> {
+    user: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    sol_vault: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+    creator_fee_vault: AccountInfo<'info>,
+    token_vault: AccountInfo<'info>,
+    user_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    sol_vault_bump: u8,
+}
+
+// Result of pricing a swap. `sol_amount` is what the user pays (buy) or
+// receives (sell); fees are on top of the curve amount on sells and
+// inside `sol_amount` on buys.
+#[derive(Default)]
+struct SwapOutcome {
+    sol_amount: u64,
+    token_amount: u64,
+    protocol_fee: u64,
+    creator_fee: u64,
+}
+
+impl SwapOutcome {
+    // SOL that enters the curve on a buy
+    fn curve_sol_in(&self) -> Result<u64> {
+        self.sol_amount
+            .checked_sub(self.protocol_fee)
+            .and_then(|amount| amount.checked_sub(self.creator_fee))
+            .ok_or_else(|| error!(AmmError::MathOverflow))
+    }
+
+    // SOL that leaves the curve on a sell
+    fn curve_sol_out(&self) -> Result<u64> {
+        self.sol_amount
+            .checked_add(self.protocol_fee)
+            .and_then(|amount| amount.checked_add(self.creator_fee))
+            .ok_or_else(|| error!(AmmError::MathOverflow))
+    }
+}
+
+fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(name.len() <= MAX_NAME_LEN, ErrorCode::NameTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::SymbolTooLong);
+    require!(uri.len() <= MAX_URI_LEN, ErrorCode::UriTooLong);
+    Ok(())
+}
+
+fn transfer_native_sol_fee(accounts: &MintAccounts, fee_lamports: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            system_program::Transfer {
+                from: accounts.payer.clone(),
+                to: accounts.fee_vault.clone(),
+            },
+        ),
+        fee_lamports,
+    )
+}
+
+// Token-2022 reallocs the mint when the metadata is written, so the extra
+// rent has to be on the account before the CPI.
+fn metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    // TLV header (type + length) + update authority + mint
+    // + 3 length-prefixed strings + empty additional_metadata vec
+    2 + 2 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
+
+fn initialize_token_metadata(
+    accounts: &MintAccounts,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let mint_info = accounts.mint.clone();
+    let new_len = mint_info.data_len() + metadata_space(&name, &symbol, &uri);
+    let required_lamports = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint_info.lamports());
+
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                system_program::Transfer {
+                    from: accounts.payer.clone(),
+                    to: mint_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    let mint_key = mint_info.key();
+    let vault_seeds: &[&[u8]] = &[b"vault", mint_key.as_ref(), &[accounts.vault_bump]];
+    let signer_seeds = &[vault_seeds];
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: accounts.token_program.clone(),
+                mint: mint_info.clone(),
+                metadata: mint_info,
+                mint_authority: accounts.vault.clone(),
+                update_authority: accounts.vault.clone(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    msg!("✅ Token metadata written to mint");
+
+    Ok(())
+}
+
+fn distribute_supply(accounts: &MintAccounts) -> Result<()> {
+    // 100% to vault, 0% to minter (fair launch like pump.fun)
+    let vault_share = TOTAL_SUPPLY;
+
+    let mint_key = accounts.mint.key();
+    let vault_seeds: &[&[u8]] = &[b"vault", mint_key.as_ref(), &[accounts.vault_bump]];
+    let signer_seeds = &[vault_seeds];
+
+    // Mint 100% to vault
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.vault_token_account.clone(),
+                authority: accounts.vault.clone(),
+            },
+            signer_seeds,
+        ),
+        vault_share,
+    )?;
+
+    msg!("✅ 100% of supply (1B tokens) minted to vault (fair launch)");
+
+    // Revoke mint authority: supply is fixed at 1B forever. The mint never
+    // had a freeze authority. Metadata update authority stays with the vault.
+    token::set_authority(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            SetAuthority {
+                current_authority: accounts.vault.clone(),
+                account_or_mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    msg!("🔒 Mint authority revoked");
+
+    Ok(())
+}
+
+fn init_pool_state(
+    pool: &mut AmmPool,
+    accounts: &PoolSeedAccounts,
+    bump: u8,
+    protocol_state: &ProtocolState,
+    launch_config: &Account<LaunchConfig>,
+) -> Result<()> {
+    pool.token_mint = accounts.token_mint.key();
+    pool.sol_vault = accounts.sol_vault.key();
+    pool.token_vault = accounts.token_vault.key();
+
+    // Snapshot the preset so later config changes don't affect this pool
+    pool.launch_config = launch_config.key();
+    pool.curve_tokens = launch_config.curve_tokens;
+    pool.trade_fee_bps = launch_config.trade_fee_bps;
+    pool.creator_fee_bps = launch_config.creator_fee_bps;
+    pool.curve_type = launch_config.curve_type;
+
+    // Allocation of the tokens left in the minting vault
+    let reserve = TOTAL_SUPPLY
+        .checked_sub(launch_config.curve_tokens)
+        .ok_or(AmmError::MathOverflow)?;
+    let reserve_share =
+        |bps: u16| ((reserve as u128) * (bps as u128) / (BPS_DENOMINATOR as u128)) as u64;
+    pool.creator_reserve_tokens = reserve_share(launch_config.reserve_creator_bps);
+    pool.burn_reserve_tokens = reserve_share(launch_config.reserve_burn_bps);
+    // Rounding dust stays with the liquidity reserve
+    pool.liquidity_reserve_tokens =
+        reserve - pool.creator_reserve_tokens - pool.burn_reserve_tokens;
+    pool.vesting_cliff_seconds = launch_config.vesting_cliff_seconds;
+    pool.vesting_duration_seconds = launch_config.vesting_duration_seconds;
+
+    // Real reserves (actual amounts in vaults)
+    pool.real_sol_reserve = launch_config.initial_sol;
+    pool.real_token_reserve = launch_config.curve_tokens;
+
+    // Virtual reserves (for price calculation) ⭐
+    pool.virtual_sol_reserve = launch_config
+        .virtual_sol_reserve
+        .checked_add(launch_config.initial_sol)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.virtual_token_reserve = launch_config.virtual_token_reserve;
+
+    pool.bump = bump;
+    pool.is_initialized = true;
+
+    // Graduation threshold comes from the preset as well
+    pool.graduation_sol_threshold = launch_config.graduation_sol_threshold;
+    pool.is_complete = false;
+    pool.is_migrated = false;
+
+    // Snapshot anti-snipe launch window
+    pool.launch_slot = Clock::get()?.slot;
+    pool.launch_window_slots = protocol_state.launch_window_slots;
+    pool.max_buy_per_wallet_lamports = protocol_state.max_buy_per_wallet_lamports;
+
+    msg!(
+        "🚀 Pool initialized with launch config #{}:",
+        launch_config.config_id
+    );
+    msg!("   Real SOL: {} lamports", pool.real_sol_reserve);
+    msg!("   Real Tokens: {} base units", pool.real_token_reserve);
+    msg!("   Virtual SOL: {} lamports", pool.virtual_sol_reserve);
+    msg!(
+        "   Virtual Tokens: {} base units",
+        pool.virtual_token_reserve
+    );
+
+    // Fully diluted market cap at the starting spot price
+    pool.refresh_market_data()?;
+    msg!(
+        "   Initial Market Cap: {} lamports (~{} SOL)",
+        pool.market_cap_lamports,
+        pool.market_cap_lamports / 1_000_000_000
+    );
+    msg!("   Curve: {:?}", pool.curve_type);
+
+    Ok(())
+}
+
+fn emit_launch_config_updated(config_key: Pubkey, config: &LaunchConfig) {
+    emit!(LaunchConfigUpdated {
+        launch_config: config_key,
+        config_id: config.config_id,
+        initial_sol: config.initial_sol,
+        curve_tokens: config.curve_tokens,
+        virtual_sol_reserve: config.virtual_sol_reserve,
+        virtual_token_reserve: config.virtual_token_reserve,
+        graduation_sol_threshold: config.graduation_sol_threshold,
+        trade_fee_bps: config.trade_fee_bps,
+        creator_fee_bps: config.creator_fee_bps,
+        reserve_liquidity_bps: config.reserve_liquidity_bps,
+        reserve_creator_bps: config.reserve_creator_bps,
+        reserve_burn_bps: config.reserve_burn_bps,
+        vesting_cliff_seconds: config.vesting_cliff_seconds,
+        vesting_duration_seconds: config.vesting_duration_seconds,
+        curve_type: config.curve_type,
+    });
+}
+
+fn init_creator_fee_vault(creator_fee_vault: &mut CreatorFeeVault, token_mint: Pubkey, bump: u8) {
+    creator_fee_vault.token_mint = token_mint;
+    creator_fee_vault.total_accrued = 0;
+    creator_fee_vault.total_claimed = 0;
+    creator_fee_vault.bump = bump;
+}
+
+// Zero-extends a program account still in its original layout to `new_len`
+// (fields were only ever appended), topping up rent from `payer`.
+fn grow_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    discriminator: &[u8],
+    legacy_len: usize,
+    new_len: usize,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::NotLegacyAccount);
+    require!(
+        account.data_len() == 8 + legacy_len
+            && account.try_borrow_data()?.starts_with(discriminator),
+        ErrorCode::NotLegacyAccount
+    );
+
+    let new_size = 8 + new_len;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    account.resize(new_size)?;
+
+    Ok(())
+}
+
+fn seed_pool(accounts: &PoolSeedAccounts, launch_config: &LaunchConfig) -> Result<()> {
+    // Transfer the preset's curve tokens FROM minting vault TO pool
+    msg!(
+        "📦 Transferring {} tokens from vault to pool...",
+        launch_config.curve_tokens
+    );
+
+    let token_mint_key = accounts.token_mint.key();
+    let vault_seeds = &[b"vault", token_mint_key.as_ref(), &[accounts.vault_bump]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: accounts.vault_token_account.clone(),
+        to: accounts.token_vault.clone(),
+        mint: accounts.token_mint.clone(),
+        authority: accounts.vault.clone(),
+    };
+    token::transfer_checked(
+        CpiContext::new_with_signer(accounts.token_program.clone(), cpi_accounts, signer_seeds),
+        launch_config.curve_tokens,
+        9,
+    )?;
+
+    msg!("✅ Tokens transferred successfully!");
+
+    // Transfer the preset's initial SOL from initializer to pool
+    msg!(
+        "💰 Transferring {} lamports from user to pool...",
+        launch_config.initial_sol
+    );
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: accounts.payer.clone(),
+                to: accounts.sol_vault.clone(),
             },
         ),
-        fee_lamports,
-    )
+        launch_config.initial_sol,
+    )?;
+
+    msg!("✅ SOL transferred successfully!");
+
+    Ok(())
+}
+
+// Protocol pause bit + per-pool halt, checked by every trading instruction
+fn require_trading_open(protocol_state: &ProtocolState, pool: &AmmPool, flag: u8) -> Result<()> {
+    protocol_state.require_not_paused(flag)?;
+    require!(!pool.halted, AmmError::PoolHalted);
+    Ok(())
+}
+
+// Exact SOL in → tokens out. Caps the final buy to the tokens left on the
+// curve and only charges the SOL needed for them.
+fn quote_buy_exact_sol(
+    pool: &AmmPool,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    sol_amount: u64,
+) -> Result<SwapOutcome> {
+    let (fee_amount, creator_fee_amount) =
+        curve::split_fees(sol_amount, fee_bps, creator_fee_bps)?;
+    let sol_amount_after_fee = sol_amount
+        .checked_sub(fee_amount)
+        .and_then(|amount| amount.checked_sub(creator_fee_amount))
+        .ok_or(AmmError::MathOverflow)?;
+
+    // ⭐ Priced by the pool's curve (VIRTUAL reserves for constant-product)
+    let token_amount = pool.curve().buy(sol_amount_after_fee)?;
+
+    if token_amount > pool.real_token_reserve {
+        let outcome =
+            quote_buy_exact_tokens(pool, fee_bps, creator_fee_bps, pool.real_token_reserve)?;
+        msg!(
+            "🏁 Final buy capped to {} tokens for {} lamports",
+            outcome.token_amount,
+            outcome.sol_amount
+        );
+        return Ok(outcome);
+    }
+
+    Ok(SwapOutcome {
+        sol_amount,
+        token_amount,
+        protocol_fee: fee_amount,
+        creator_fee: creator_fee_amount,
+    })
+}
+
+// Exact tokens out → SOL in (rounded up in the pool's favour)
+fn quote_buy_exact_tokens(
+    pool: &AmmPool,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    token_amount: u64,
+) -> Result<SwapOutcome> {
+    let required_after_fee = pool.curve().buy_exact_out(token_amount)?;
+
+    let sol_amount = curve::gross_up_for_fees(required_after_fee, fee_bps, creator_fee_bps)?;
+    let (fee_amount, creator_fee_amount) =
+        curve::split_fees(sol_amount, fee_bps, creator_fee_bps)?;
+
+    Ok(SwapOutcome {
+        sol_amount,
+        token_amount,
+        protocol_fee: fee_amount,
+        creator_fee: creator_fee_amount,
+    })
+}
+
+// Exact tokens in → SOL out. Fee is taken from the SOL output, so it is
+// always in lamports.
+fn quote_sell_exact_tokens(
+    pool: &AmmPool,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    token_amount: u64,
+) -> Result<SwapOutcome> {
+    // ⭐ Price the FULL token input on the pool's curve
+    let sol_amount_before_fee = pool.curve().sell(token_amount)?;
+
+    let (fee_amount, creator_fee_amount) =
+        curve::split_fees(sol_amount_before_fee, fee_bps, creator_fee_bps)?;
+    let sol_amount = sol_amount_before_fee
+        .checked_sub(fee_amount)
+        .and_then(|amount| amount.checked_sub(creator_fee_amount))
+        .ok_or(AmmError::MathOverflow)?;
+
+    Ok(SwapOutcome {
+        sol_amount,
+        token_amount,
+        protocol_fee: fee_amount,
+        creator_fee: creator_fee_amount,
+    })
+}
+
+// Exact SOL out → tokens in (rounded up in the pool's favour). Rounding
+// dust on the fee split goes to the protocol fee.
+fn quote_sell_exact_sol(
+    pool: &AmmPool,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    sol_amount: u64,
+) -> Result<SwapOutcome> {
+    let sol_amount_before_fee = curve::gross_up_for_fees(sol_amount, fee_bps, creator_fee_bps)?;
+    let (_, creator_fee_amount) =
+        curve::split_fees(sol_amount_before_fee, fee_bps, creator_fee_bps)?;
+    let fee_amount = sol_amount_before_fee
+        .checked_sub(sol_amount)
+        .and_then(|amount| amount.checked_sub(creator_fee_amount))
+        .ok_or(AmmError::MathOverflow)?;
+
+    let token_amount = pool.curve().sell_exact_out(sol_amount_before_fee)?;
+
+    Ok(SwapOutcome {
+        sol_amount,
+        token_amount,
+        protocol_fee: fee_amount,
+        creator_fee: creator_fee_amount,
+    })
+}
+
+// Exact-input curve buy used by swap_sol_to_tokens, launch_meme and the
+// dev buy. Callers emit events and check graduation.
+fn execute_buy(
+    accounts: &BuyAccounts,
+    pool: &mut AmmPool,
+    creator_fee_vault: &mut CreatorFeeVault,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    sol_amount: u64,
+    min_token_amount: u64,
+) -> Result<SwapOutcome> {
+    require!(sol_amount > 0, AmmError::InvalidAmount);
+    require!(pool.is_initialized, AmmError::PoolNotInitialized);
+    require!(!pool.is_complete, AmmError::CurveComplete);
+
+    let outcome = quote_buy_exact_sol(pool, fee_bps, creator_fee_bps, sol_amount)?;
+    require!(
+        outcome.token_amount >= min_token_amount,
+        AmmError::SlippageExceeded
+    );
+
+    settle_buy(accounts, pool, creator_fee_vault, &outcome)?;
+
+    Ok(outcome)
+}
+
+// Moves SOL/fees in and tokens out, then updates reserves
+fn settle_buy(
+    accounts: &BuyAccounts,
+    pool: &mut AmmPool,
+    creator_fee_vault: &mut CreatorFeeVault,
+    outcome: &SwapOutcome,
+) -> Result<()> {
+    let sol_amount_after_fee = outcome.curve_sol_in()?;
+
+    msg!(
+        "💫 Swapping {} SOL for {} tokens (fee: {}, creator fee: {})",
+        outcome.sol_amount,
+        outcome.token_amount,
+        outcome.protocol_fee,
+        outcome.creator_fee
+    );
+    msg!(
+        "   Price: {} virtual SOL / {} virtual tokens",
+        pool.virtual_sol_reserve,
+        pool.virtual_token_reserve
+    );
+
+    // Transfer SOL (net of fee) into the curve
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: accounts.user.clone(),
+                to: accounts.sol_vault.clone(),
+            },
+        ),
+        sol_amount_after_fee,
+    )?;
+
+    // Transfer fee to the protocol fee vault
+    if outcome.protocol_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: accounts.user.clone(),
+                    to: accounts.fee_vault.clone(),
+                },
+            ),
+            outcome.protocol_fee,
+        )?;
+    }
+
+    // Transfer creator fee to the per-token creator fee vault
+    if outcome.creator_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: accounts.user.clone(),
+                    to: accounts.creator_fee_vault.clone(),
+                },
+            ),
+            outcome.creator_fee,
+        )?;
+        creator_fee_vault.total_accrued = creator_fee_vault
+            .total_accrued
+            .checked_add(outcome.creator_fee)
+            .ok_or(AmmError::MathOverflow)?;
+    }
+
+    // Transfer tokens
+    let token_mint_key = pool.token_mint;
+    let seeds = &[AMM_POOL_SEED, token_mint_key.as_ref(), &[pool.bump]];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            TransferChecked {
+                from: accounts.token_vault.clone(),
+                to: accounts.user_token_account.clone(),
+                mint: accounts.token_mint.clone(),
+                authority: accounts.pool.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        outcome.token_amount,
+        9,
+    )?;
+
+    apply_buy_reserves(pool, outcome)?;
+    pool.record_trade(outcome.sol_amount, true)?;
+
+    msg!(
+        "📊 New reserves - Real: {} SOL / {} tokens, Virtual: {} SOL / {} tokens",
+        pool.real_sol_reserve,
+        pool.real_token_reserve,
+        pool.virtual_sol_reserve,
+        pool.virtual_token_reserve
+    );
+
+    Ok(())
+}
+
+// Moves tokens in and SOL/fees out of the curve, then updates reserves
+fn settle_sell(
+    accounts: &SellAccounts,
+    pool: &mut AmmPool,
+    creator_fee_vault: &mut CreatorFeeVault,
+    outcome: &SwapOutcome,
+) -> Result<()> {
+    // Reserves first: fails early if the curve can't cover the gross SOL
+    apply_sell_reserves(pool, outcome)?;
+    pool.record_trade(outcome.curve_sol_out()?, false)?;
+
+    msg!(
+        "💫 Swapping {} tokens for {} SOL (fee: {}, creator fee: {})",
+        outcome.token_amount,
+        outcome.sol_amount,
+        outcome.protocol_fee,
+        outcome.creator_fee
+    );
+
+    // Transfer tokens from user to pool
+    token::transfer_checked(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            TransferChecked {
+                from: accounts.user_token_account.clone(),
+                to: accounts.token_vault.clone(),
+                mint: accounts.token_mint.clone(),
+                authority: accounts.user.clone(),
+            },
+        ),
+        outcome.token_amount,
+        9,
+    )?;
+
+    // Transfer SOL from vault to user using proper system program CPI
+    let token_mint_key = pool.token_mint;
+    let vault_signer_seeds = &[
+        POOL_SOL_VAULT_SEED,
+        token_mint_key.as_ref(),
+        &[accounts.sol_vault_bump],
+    ];
+    let vault_signer = &[&vault_signer_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            system_program::Transfer {
+                from: accounts.sol_vault.clone(),
+                to: accounts.user.clone(),
+            },
+            vault_signer,
+        ),
+        outcome.sol_amount,
+    )?;
+
+    // Transfer fee from the curve to the protocol fee vault
+    if outcome.protocol_fee > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::Transfer {
+                    from: accounts.sol_vault.clone(),
+                    to: accounts.fee_vault.clone(),
+                },
+                vault_signer,
+            ),
+            outcome.protocol_fee,
+        )?;
+    }
+
+    // Transfer creator fee from the curve to the creator fee vault
+    if outcome.creator_fee > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::Transfer {
+                    from: accounts.sol_vault.clone(),
+                    to: accounts.creator_fee_vault.clone(),
+                },
+                vault_signer,
+            ),
+            outcome.creator_fee,
+        )?;
+        creator_fee_vault.total_accrued = creator_fee_vault
+            .total_accrued
+            .checked_add(outcome.creator_fee)
+            .ok_or(AmmError::MathOverflow)?;
+    }
+
+    msg!(
+        "📊 New reserves - Real: {} SOL / {} tokens, Virtual: {} SOL / {} tokens",
+        pool.real_sol_reserve,
+        pool.real_token_reserve,
+        pool.virtual_sol_reserve,
+        pool.virtual_token_reserve
+    );
+
+    Ok(())
+}
+
+// Update BOTH real and virtual reserves (fee is not part of the curve)
+fn apply_buy_reserves(pool: &mut AmmPool, outcome: &SwapOutcome) -> Result<()> {
+    let sol_amount_after_fee = outcome.curve_sol_in()?;
+
+    pool.real_sol_reserve = pool
+        .real_sol_reserve
+        .checked_add(sol_amount_after_fee)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.real_token_reserve = pool
+        .real_token_reserve
+        .checked_sub(outcome.token_amount)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.virtual_sol_reserve = pool
+        .virtual_sol_reserve
+        .checked_add(sol_amount_after_fee)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.virtual_token_reserve = pool
+        .virtual_token_reserve
+        .checked_sub(outcome.token_amount)
+        .ok_or(AmmError::MathOverflow)?;
+
+    Ok(())
+}
+
+// Update BOTH real and virtual reserves (gross SOL leaves the curve)
+fn apply_sell_reserves(pool: &mut AmmPool, outcome: &SwapOutcome) -> Result<()> {
+    let sol_amount_before_fee = outcome.curve_sol_out()?;
+
+    require!(
+        sol_amount_before_fee < pool.real_sol_reserve,
+        AmmError::InsufficientLiquidity
+    );
+
+    pool.real_sol_reserve = pool
+        .real_sol_reserve
+        .checked_sub(sol_amount_before_fee)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.real_token_reserve = pool
+        .real_token_reserve
+        .checked_add(outcome.token_amount)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.virtual_sol_reserve = pool
+        .virtual_sol_reserve
+        .checked_sub(sol_amount_before_fee)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.virtual_token_reserve = pool
+        .virtual_token_reserve
+        .checked_add(outcome.token_amount)
+        .ok_or(AmmError::MathOverflow)?;
+
+    Ok(())
+}
+
+// Quote without touching the pool: applies the trade to a copy.
+// Price impact is the execution price (fees excluded) vs the spot price.
+fn build_quote(pool: &AmmPool, outcome: &SwapOutcome, is_buy: bool) -> Result<QuoteResult> {
+    let spot_price = pool.curve().spot_price()?;
+    let mut post_trade = pool.clone();
+
+    let (input_amount, output_amount, curve_sol) = if is_buy {
+        apply_buy_reserves(&mut post_trade, outcome)?;
+        (outcome.sol_amount, outcome.token_amount, outcome.curve_sol_in()?)
+    } else {
+        apply_sell_reserves(&mut post_trade, outcome)?;
+        (outcome.token_amount, outcome.sol_amount, outcome.curve_sol_out()?)
+    };
+
+    let price_impact_bps = if outcome.token_amount == 0 || spot_price == 0 {
+        0
+    } else {
+        let execution_price = (curve_sol as u128)
+            .checked_mul(curve::WAD)
+            .ok_or(AmmError::MathOverflow)?
+            / (outcome.token_amount as u128);
+        let impact = execution_price
+            .abs_diff(spot_price)
+            .saturating_mul(BPS_DENOMINATOR as u128)
+            / spot_price;
+        impact.min(u64::MAX as u128) as u64
+    };
+
+    Ok(QuoteResult {
+        input_amount,
+        output_amount,
+        protocol_fee: outcome.protocol_fee,
+        creator_fee: outcome.creator_fee,
+        price_impact_bps,
+        real_sol_reserve: post_trade.real_sol_reserve,
+        real_token_reserve: post_trade.real_token_reserve,
+        virtual_sol_reserve: post_trade.virtual_sol_reserve,
+        virtual_token_reserve: post_trade.virtual_token_reserve,
+    })
+}
+
+// Graduation: curve sold out or SOL threshold reached
+fn check_graduation(pool: &mut AmmPool, pool_key: Pubkey) {
+    let threshold_reached = pool.graduation_sol_threshold > 0
+        && pool.real_sol_reserve >= pool.graduation_sol_threshold;
+    if !pool.is_complete && (pool.real_token_reserve == 0 || threshold_reached) {
+        pool.is_complete = true;
+        pool.progress_bps = BPS_DENOMINATOR as u16;
+
+        msg!("🎓 Bonding curve complete - trading frozen");
+
+        emit!(CurveCompleted {
+            pool: pool_key,
+            token_mint: pool.token_mint,
+            real_sol: pool.real_sol_reserve,
+            real_tokens: pool.real_token_reserve,
+            virtual_sol: pool.virtual_sol_reserve,
+            virtual_tokens: pool.virtual_token_reserve,
+        });
+    }
+}
+
+// Babylonian integer square root (floor)
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = value.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// ==================== ACCOUNT STRUCTS ====================
+
+#[derive(Accounts)]
+pub struct InitializeProtocolState<'info> {
+    #[account(init_if_needed, payer = authority, space = 128, seeds = [b"protocol_state_v2"], bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: UncheckedAccount<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::MemeLaunchpad>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::NotUpgradeAuthority)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolState<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+#[derive(Accounts)]
+#[instruction(config_id: u16)]
+pub struct CreateLaunchConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LaunchConfig::LEN,
+        seeds = [LAUNCH_CONFIG_SEED, config_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateLaunchConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCH_CONFIG_SEED, launch_config.config_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetPoolHalted<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, AmmPool>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        constraint = protocol_state.pending_authority == pending_authority.key() @ ErrorCode::NotPendingAuthority
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawProtocolFees<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
+    #[account(mut)]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(meme_id: [u8; 32])]
+pub struct MintMemeToken<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+    #[account(init, payer = minter, space = 8 + MemeTokenState::LEN, seeds = [b"meme_token_state", meme_id.as_ref()], bump)]
+    pub meme_token_state: Account<'info, MemeTokenState>,
+    #[account(init, payer = minter, mint::decimals = 9, mint::authority = vault, extensions::metadata_pointer::authority = vault, extensions::metadata_pointer::metadata_address = mint, seeds = [b"meme_mint", meme_id.as_ref()], bump, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(seeds = [b"vault", mint.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+    #[account(init_if_needed, payer = minter, associated_token::mint = mint, associated_token::authority = vault, associated_token::token_program = token_program)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: UncheckedAccount<'info>,
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MintMemeToken<'info> {
+    fn mint_accounts(&self, vault_bump: u8) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.minter.to_account_info(),
+            mint: self.mint.to_account_info(),
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            vault_bump,
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(meme_id: [u8; 32])]
+pub struct LaunchMeme<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MemeTokenState::LEN,
+        seeds = [b"meme_token_state", meme_id.as_ref()],
+        bump
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 9,
+        mint::authority = vault,
+        extensions::metadata_pointer::authority = vault,
+        extensions::metadata_pointer::metadata_address = mint,
+        seeds = [b"meme_mint", meme_id.as_ref()],
+        bump,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(seeds = [b"vault", mint.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        seeds = [LAUNCH_CONFIG_SEED, launch_config.config_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Box<Account<'info, LaunchConfig>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + AmmPool::LEN,
+        seeds = [AMM_POOL_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SOL_VAULT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = pool,
+        seeds = [POOL_TOKEN_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::token_program = token_program
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + CreatorFeeVault::LEN,
+        seeds = [CREATOR_FEE_VAULT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    // Receives the optional initial buy
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LaunchMeme<'info> {
+    fn mint_accounts(&self, vault_bump: u8) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.creator.to_account_info(),
+            mint: self.mint.to_account_info(),
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            vault_bump,
+        }
+    }
+
+    fn pool_seed_accounts(&self, vault_bump: u8) -> PoolSeedAccounts<'info> {
+        PoolSeedAccounts {
+            payer: self.creator.to_account_info(),
+            token_mint: self.mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            vault_bump,
+        }
+    }
+
+    fn buy_accounts(&self) -> BuyAccounts<'info> {
+        BuyAccounts {
+            user: self.creator.to_account_info(),
+            pool: self.pool.to_account_info(),
+            token_mint: self.mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            creator_fee_vault: self.creator_fee_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            user_token_account: self.creator_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    // Only mints created by mint_meme_token can get a pool, and only their
+    // minter can open it (the dev buy skips the launch window cap)
+    #[account(
+        mut,
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = meme_token_state.is_initialized @ AmmError::MemeNotInitialized,
+        constraint = meme_token_state.minter == initializer.key() @ ErrorCode::Unauthorized
+    )]
+    pub meme_token_state: Account<'info, MemeTokenState>,
+
+    #[account(
+        init,
+        payer = initializer,
+        space = 8 + AmmPool::LEN,
+        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, AmmPool>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SOL_VAULT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = initializer,
+        token::mint = token_mint,
+        token::authority = pool,
+        seeds = [POOL_TOKEN_VAULT_SEED, token_mint.key().as_ref()],
+        bump,
+        token::token_program = token_program
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = initializer,
+        space = 8 + CreatorFeeVault::LEN,
+        seeds = [CREATOR_FEE_VAULT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Account<'info, CreatorFeeVault>,
+
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        seeds = [LAUNCH_CONFIG_SEED, launch_config.config_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    // Receives the optional dev buy
+    #[account(
+        init_if_needed,
+        payer = initializer,
+        associated_token::mint = token_mint,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePool<'info> {
+    fn buy_accounts(&self) -> BuyAccounts<'info> {
+        BuyAccounts {
+            user: self.initializer.to_account_info(),
+            pool: self.pool.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            creator_fee_vault: self.creator_fee_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            user_token_account: self.initializer_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+
+    fn pool_seed_accounts(&self, vault_bump: u8) -> PoolSeedAccounts<'info> {
+        PoolSeedAccounts {
+            payer: self.initializer.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            vault_bump,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SwapSolForTokens<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint,
+        has_one = sol_vault @ AmmError::InvalidVault,
+        has_one = token_vault @ AmmError::InvalidVault
+    )]
+    pub pool: Account<'info, AmmPool>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [POOL_SOL_VAULT_SEED, pool.token_mint.as_ref()], bump)]
+    pub sol_vault: SystemAccount<'info>,
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
+    #[account(mut, seeds = [CREATOR_FEE_VAULT_SEED, pool.token_mint.as_ref()], bump = creator_fee_vault.bump)]
+    pub creator_fee_vault: Account<'info, CreatorFeeVault>,
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.token_mint @ AmmError::InvalidMint,
+        constraint = user_token_account.owner == user.key() @ AmmError::InvalidTokenAccount
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    // Only required during the launch window; omit it afterwards
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserLaunchBuys::LEN,
+        seeds = [LAUNCH_BUY_SEED, pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub launch_buys: Option<Account<'info, UserLaunchBuys>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SwapSolForTokens<'info> {
+    // Anti-snipe: cap SOL spent per wallet during the launch window
+    fn record_launch_buy(&mut self, bump: Option<u8>, sol_amount: u64) -> Result<()> {
+        if !self.pool.in_launch_window(Clock::get()?.slot) {
+            return Ok(());
+        }
+
+        let launch_buys = self
+            .launch_buys
+            .as_mut()
+            .ok_or(AmmError::LaunchBuysAccountRequired)?;
+        if launch_buys.pool == Pubkey::default() {
+            launch_buys.pool = self.pool.key();
+            launch_buys.user = self.user.key();
+            launch_buys.bump = bump.ok_or(AmmError::LaunchBuysAccountRequired)?;
+        }
+
+        launch_buys.sol_spent = launch_buys
+            .sol_spent
+            .checked_add(sol_amount)
+            .ok_or(AmmError::MathOverflow)?;
+        require!(
+            launch_buys.sol_spent <= self.pool.max_buy_per_wallet_lamports,
+            AmmError::LaunchBuyLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    fn buy_accounts(&self) -> BuyAccounts<'info> {
+        BuyAccounts {
+            user: self.user.to_account_info(),
+            pool: self.pool.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            creator_fee_vault: self.creator_fee_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            user_token_account: self.user_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SwapTokensForSol<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint,
+        has_one = sol_vault @ AmmError::InvalidVault,
+        has_one = token_vault @ AmmError::InvalidVault
+    )]
+    pub pool: Account<'info, AmmPool>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: SOL vault PDA - validated by seeds in instruction
+    #[account(
+        mut,
+        seeds = [POOL_SOL_VAULT_SEED, pool.token_mint.as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT_SEED, pool.token_mint.as_ref()],
+        bump = creator_fee_vault.bump
+    )]
+    pub creator_fee_vault: Account<'info, CreatorFeeVault>,
+
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.token_mint @ AmmError::InvalidMint,
+        constraint = user_token_account.owner == user.key() @ AmmError::InvalidTokenAccount
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SwapTokensForSol<'info> {
+    fn sell_accounts(&self, sol_vault_bump: u8) -> SellAccounts<'info> {
+        SellAccounts {
+            user: self.user.to_account_info(),
+            token_mint: self.token_mint.to_account_info(),
+            sol_vault: self.sol_vault.to_account_info(),
+            fee_vault: self.fee_vault.to_account_info(),
+            creator_fee_vault: self.creator_fee_vault.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            user_token_account: self.user_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            sol_vault_bump,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseLaunchBuys<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, AmmPool>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [LAUNCH_BUY_SEED, pool.key().as_ref(), user.key().as_ref()],
+        bump = launch_buys.bump
+    )]
+    pub launch_buys: Account<'info, UserLaunchBuys>,
+}
+
+#[derive(Accounts)]
+pub struct QuotePool<'info> {
+    #[account(
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, AmmPool>,
+
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Account<'info, ProtocolState>,
 }
 
-fn distribute_supply(ctx: &Context<MintMemeToken>) -> Result<()> {
-    const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000; // 1 billion tokens
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
 
-    // 100% to vault, 0% to minter (fair launch like pump.fun)
-    let vault_share = TOTAL_SUPPLY;
+    #[account(
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        has_one = minter @ ErrorCode::Unauthorized
+    )]
+    pub meme_token_state: Account<'info, MemeTokenState>,
 
-    let mint_key = ctx.accounts.mint.key();
-    let vault_seeds: &[&[u8]] = &[b"vault", mint_key.as_ref(), &[ctx.bumps.vault]];
-    let signer_seeds = &[vault_seeds];
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT_SEED, meme_token_state.mint.as_ref()],
+        bump = creator_fee_vault.bump
+    )]
+    pub creator_fee_vault: Account<'info, CreatorFeeVault>,
+}
 
-    // Mint 100% to vault
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.vault.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        vault_share,
-    )?;
+#[derive(Accounts)]
+pub struct BurnReserve<'info> {
+    #[account(mut)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    msg!("✅ 100% of supply (1B tokens) minted to vault (fair launch)");
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint
+    )]
+    pub pool: Box<Account<'info, AmmPool>>,
 
-    Ok(())
-}
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
 
-// ==================== ACCOUNT STRUCTS ====================
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
 
 #[derive(Accounts)]
-pub struct InitializeProtocolState<'info> {
-    #[account(init_if_needed, payer = authority, space = 128, seeds = [b"protocol_state_v2"], bump)]
-    pub protocol_state: Account<'info, ProtocolState>,
+pub struct FundVesting<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(mut, seeds = [b"fee_vault"], bump)]
-    pub fee_vault: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint
+    )]
+    pub pool: Box<Account<'info, AmmPool>>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VestingAccount::LEN,
+        seeds = [VESTING_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub vesting: Box<Account<'info, VestingAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = vesting,
+        seeds = [VESTING_TOKEN_SEED, token_mint.key().as_ref()],
+        bump,
+        token::token_program = token_program
+    )]
+    pub vesting_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(meme_id: [u8; 32])]
-pub struct MintMemeToken<'info> {
+pub struct ClaimVested<'info> {
     #[account(mut)]
     pub minter: Signer<'info>,
-    #[account(init, payer = minter, space = 8 + 32 + 32 + 32 + 8 + 1 + 1, seeds = [b"meme_token_state", meme_id.as_ref()], bump)]
-    pub meme_token_state: Account<'info, MemeTokenState>,
-    #[account(init, payer = minter, mint::decimals = 9, mint::authority = vault, mint::freeze_authority = vault, seeds = [b"meme_mint", meme_id.as_ref()], bump, mint::token_program = token_program)]
-    pub mint: InterfaceAccount<'info, Mint>,
-    #[account(seeds = [b"vault", mint.key().as_ref()], bump)]
+
+    #[account(
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        has_one = minter @ ErrorCode::Unauthorized,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEED, token_mint.key().as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Box<Account<'info, VestingAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_TOKEN_SEED, token_mint.key().as_ref()],
+        bump = vesting.token_account_bump
+    )]
+    pub vesting_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = minter,
+        associated_token::mint = token_mint,
+        associated_token::authority = minter,
+        associated_token::token_program = token_program
+    )]
+    pub minter_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReserveStatusView<'info> {
+    #[account(
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, AmmPool>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", pool.token_mint.as_ref()],
+        bump
+    )]
     pub vault: UncheckedAccount<'info>,
-    #[account(init_if_needed, payer = minter, associated_token::mint = mint, associated_token::authority = vault, associated_token::token_program = token_program)]
+
+    #[account(
+        constraint = vault_token_account.mint == pool.token_mint @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct BurnMemeTokens<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(mut)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = holder_token_account.owner == holder.key() @ AmmError::InvalidTokenAccount
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Account<'info, MemeTokenState>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint,
+        has_one = sol_vault @ AmmError::InvalidVault,
+        has_one = token_vault @ AmmError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, AmmPool>>,
+
+    #[account(mut)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [POOL_SOL_VAULT_SEED, pool.token_mint.as_ref()], bump)]
+    pub sol_vault: SystemAccount<'info>,
+
     #[account(mut, seeds = [b"fee_vault"], bump)]
-    pub fee_vault: UncheckedAccount<'info>,
-    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
-    pub protocol_state: Account<'info, ProtocolState>,
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 #[derive(Accounts)]
-pub struct InitializePool<'info> {
+pub struct UpgradeMemeTokenState<'info> {
     #[account(mut)]
-    pub initializer: Signer<'info>,
+    pub payer: Signer<'info>,
 
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Owner, discriminator and legacy size checked in the handler
+    #[account(mut)]
+    pub meme_token_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpgradeAmmPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_state_v2"],
+        bump = protocol_state.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Legacy AmmPool - validated by seeds, owner, discriminator and
+    /// size in the handler
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [LAUNCH_CONFIG_SEED, DEFAULT_LAUNCH_CONFIG_ID.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Box<Account<'info, LaunchConfig>>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    // The legacy supply left here becomes the pool's liquidity reserve
+    #[account(
+        constraint = vault_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Must already be upgraded with upgrade_meme_token_state
+    #[account(
+        mut,
+        seeds = [b"meme_token_state", meme_token_state.meme_id.as_ref()],
+        bump = meme_token_state.bump,
+        constraint = meme_token_state.mint == token_mint.key() @ AmmError::InvalidMint
+    )]
+    pub meme_token_state: Box<Account<'info, MemeTokenState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CreatorFeeVault::LEN,
+        seeds = [CREATOR_FEE_VAULT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint,
+        has_one = sol_vault @ AmmError::InvalidVault,
+        has_one = token_vault @ AmmError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SOL_VAULT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_TOKEN_VAULT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Minting vault PDA - validated by seeds
+    #[account(
+        seeds = [b"vault", token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == token_mint.key() @ AmmError::InvalidMint,
+        constraint = vault_token_account.owner == vault.key() @ AmmError::InvalidVault
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
-        payer = initializer,
-        space = 8 + AmmPool::LEN,
-        seeds = [AMM_POOL_SEED, token_mint.key().as_ref()],
+        payer = payer,
+        space = 8 + CpPool::LEN,
+        seeds = [CP_POOL_SEED, token_mint.key().as_ref()],
         bump
     )]
-    pub pool: Account<'info, AmmPool>,
+    pub cp_pool: Box<Account<'info, CpPool>>,
 
     #[account(
         mut,
-        seeds = [POOL_SOL_VAULT_SEED, token_mint.key().as_ref()],
+        seeds = [CP_SOL_VAULT_SEED, token_mint.key().as_ref()],
         bump
     )]
-    pub sol_vault: SystemAccount<'info>,
+    pub cp_sol_vault: SystemAccount<'info>,
 
     #[account(
         init,
-        payer = initializer,
+        payer = payer,
         token::mint = token_mint,
-        token::authority = pool,
-        seeds = [POOL_TOKEN_VAULT_SEED, token_mint.key().as_ref()],
+        token::authority = cp_pool,
+        seeds = [CP_TOKEN_VAULT_SEED, token_mint.key().as_ref()],
         bump,
         token::token_program = token_program
     )]
-    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    pub cp_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Minting vault PDA - validated by seeds
     #[account(
-        seeds = [b"vault", token_mint.key().as_ref()],
-        bump
+        init,
+        payer = payer,
+        mint::decimals = 9,
+        mint::authority = cp_pool,
+        seeds = [LP_MINT_SEED, token_mint.key().as_ref()],
+        bump,
+        mint::token_program = token_program
     )]
-    pub vault: UncheckedAccount<'info>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// CHECK: Vault's token account - we only read from it for transfer
-    #[account(mut)]
-    pub vault_token_account: UncheckedAccount<'info>,
+    // Owned by cp_pool with no instruction able to move it → LP is locked
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = cp_pool,
+        seeds = [LP_LOCK_SEED, token_mint.key().as_ref()],
+        bump,
+        token::token_program = token_program
+    )]
+    pub lp_lock_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token2022>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SwapSolForTokens<'info> {
+pub struct CpSwap<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
-    #[account(mut, seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()], bump = pool.bump)]
-    pub pool: Account<'info, AmmPool>,
-    pub token_mint: InterfaceAccount<'info, Mint>,
-    #[account(mut, seeds = [POOL_SOL_VAULT_SEED, pool.token_mint.as_ref()], bump)]
-    pub sol_vault: SystemAccount<'info>,
-    #[account(mut)]
-    pub token_vault: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut, constraint = user_token_account.mint == pool.token_mint, constraint = user_token_account.owner == user.key())]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token2022>,
-    pub system_program: Program<'info, System>,
-}
 
-#[derive(Accounts)]
-pub struct SwapTokensForSol<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
+    #[account(seeds = [b"protocol_state_v2"], bump = protocol_state.bump)]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
 
     #[account(
         mut,
-        seeds = [AMM_POOL_SEED, pool.token_mint.as_ref()],
-        bump = pool.bump
+        seeds = [CP_POOL_SEED, cp_pool.token_mint.as_ref()],
+        bump = cp_pool.bump,
+        has_one = token_mint @ AmmError::InvalidMint,
+        has_one = sol_vault @ AmmError::InvalidVault,
+        has_one = token_vault @ AmmError::InvalidVault
     )]
-    pub pool: Account<'info, AmmPool>,
+    pub cp_pool: Box<Account<'info, CpPool>>,
 
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    // Curve pool this one migrated from; its `halted` flag covers both
+    #[account(seeds = [AMM_POOL_SEED, cp_pool.token_mint.as_ref()], bump = pool.bump)]
+    pub pool: Box<Account<'info, AmmPool>>,
 
-    /// CHECK: SOL vault PDA - validated by seeds in instruction
-    #[account(
-        mut,
-        seeds = [POOL_SOL_VAULT_SEED, pool.token_mint.as_ref()],
-        bump
-    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [CP_SOL_VAULT_SEED, cp_pool.token_mint.as_ref()], bump)]
     pub sol_vault: SystemAccount<'info>,
 
     #[account(mut)]
-    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == pool.token_mint,
-        constraint = user_token_account.owner == user.key()
+        constraint = user_token_account.mint == cp_pool.token_mint @ AmmError::InvalidMint,
+        constraint = user_token_account.owner == user.key() @ AmmError::InvalidTokenAccount
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
@@ -645,6 +4084,172 @@ pub struct ProtocolState {
     pub authority: Pubkey,
     pub fee_lamports: u64,
     pub bump: u8,
+    pub pending_authority: Pubkey,
+    // Defaults for new LaunchConfigs
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub graduation_sol_threshold: u64,
+    pub launch_window_slots: u64,
+    pub max_buy_per_wallet_lamports: u64,
+    pub paused: u8, // PAUSE_* bits
+}
+
+impl ProtocolState {
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        if self.paused & flag == 0 {
+            return Ok(());
+        }
+        match flag {
+            PAUSE_MINT => err!(ErrorCode::MintingPaused),
+            PAUSE_POOL_CREATION => err!(ErrorCode::PoolCreationPaused),
+            PAUSE_BUYS => err!(ErrorCode::BuysPaused),
+            PAUSE_MIGRATION => err!(ErrorCode::MigrationPaused),
+            _ => err!(ErrorCode::SellsPaused),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProtocolConfigParams {
+    pub fee_lamports: Option<u64>,
+    pub trade_fee_bps: Option<u16>,
+    pub creator_fee_bps: Option<u16>,
+    pub graduation_sol_threshold: Option<u64>,
+    pub launch_window_slots: Option<u64>,
+    pub max_buy_per_wallet_lamports: Option<u64>,
+}
+
+// Pool preset; pools snapshot these values at creation
+#[account]
+pub struct LaunchConfig {
+    pub config_id: u16,
+    pub initial_sol: u64,
+    pub curve_tokens: u64,
+    // Virtual SOL on top of initial_sol (pool starts at virtual + initial)
+    pub virtual_sol_reserve: u64,
+    pub virtual_token_reserve: u64,
+    pub graduation_sol_threshold: u64,
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub bump: u8,
+    // Split of the non-curve supply left in the minting vault (sums to 100%)
+    pub reserve_liquidity_bps: u16,
+    pub reserve_creator_bps: u16,
+    pub reserve_burn_bps: u16,
+    // Creator allocation vesting, measured from mint time
+    pub vesting_cliff_seconds: i64,
+    pub vesting_duration_seconds: i64,
+    // Price curve; pool creators can't pick their own
+    pub curve_type: CurveType,
+}
+
+impl LaunchConfig {
+    pub const LEN: usize =
+        2 + 8 + 8 + 8 + 8 + 8 + 2 + 2 + 1 + 2 + 2 + 2 + 8 + 8 + CurveType::LEN;
+
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.curve_tokens > 0 && self.curve_tokens <= TOTAL_SUPPLY,
+            ErrorCode::InvalidLaunchConfig
+        );
+        // seed_pool funds an empty sol_vault PDA, which must be rent-exempt
+        require!(
+            self.initial_sol >= Rent::get()?.minimum_balance(0),
+            ErrorCode::InvalidLaunchConfig
+        );
+        // Constant-product curves must be able to sell out
+        require!(
+            self.virtual_sol_reserve > 0 && self.virtual_token_reserve > self.curve_tokens,
+            ErrorCode::InvalidLaunchConfig
+        );
+        require!(
+            (self.trade_fee_bps as u32) + (self.creator_fee_bps as u32)
+                <= MAX_TRADE_FEE_BPS as u32,
+            ErrorCode::FeeTooHigh
+        );
+        require!(
+            (self.reserve_liquidity_bps as u64)
+                + (self.reserve_creator_bps as u64)
+                + (self.reserve_burn_bps as u64)
+                == BPS_DENOMINATOR,
+            ErrorCode::InvalidLaunchConfig
+        );
+        require!(
+            self.vesting_cliff_seconds >= 0
+                && self.vesting_duration_seconds > 0
+                && self.vesting_cliff_seconds <= self.vesting_duration_seconds,
+            ErrorCode::InvalidLaunchConfig
+        );
+
+        // The whole curve must be priceable, or the last buys would fail.
+        // Buying it out must also cost at least the graduation threshold, so
+        // no curve graduates a pool for a few lamports.
+        self.curve_type.validate()?;
+        let virtual_sol = self
+            .virtual_sol_reserve
+            .checked_add(self.initial_sol)
+            .ok_or(AmmError::MathOverflow)?;
+        let full_curve_cost = self
+            .curve_type
+            .curve(virtual_sol, self.virtual_token_reserve, 0)
+            .buy_exact_out(self.curve_tokens)
+            .map_err(|_| error!(AmmError::InvalidCurveParams))?;
+        require!(
+            full_curve_cost >= self.graduation_sol_threshold,
+            ErrorCode::InvalidLaunchConfig
+        );
+        Ok(())
+    }
+}
+
+// Returned by quote_buy / quote_sell. Reserves are post-trade.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QuoteResult {
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub price_impact_bps: u64,
+    pub real_sol_reserve: u64,
+    pub real_token_reserve: u64,
+    pub virtual_sol_reserve: u64,
+    pub virtual_token_reserve: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LaunchConfigParams {
+    pub initial_sol: Option<u64>,
+    pub curve_tokens: Option<u64>,
+    pub virtual_sol_reserve: Option<u64>,
+    pub virtual_token_reserve: Option<u64>,
+    pub graduation_sol_threshold: Option<u64>,
+    pub trade_fee_bps: Option<u16>,
+    pub creator_fee_bps: Option<u16>,
+    pub reserve_liquidity_bps: Option<u16>,
+    pub reserve_creator_bps: Option<u16>,
+    pub reserve_burn_bps: Option<u16>,
+    pub vesting_cliff_seconds: Option<i64>,
+    pub vesting_duration_seconds: Option<i64>,
+    pub curve_type: Option<CurveType>,
+}
+
+// Returned by reserve_status. The three shares are what the allocation
+// still owes (each drops to 0 once paid out); `unallocated` is any vault
+// balance beyond them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReserveStatus {
+    pub vault_balance: u64,
+    pub liquidity_reserve_tokens: u64,
+    pub creator_reserve_tokens: u64,
+    pub burn_reserve_tokens: u64,
+    pub unallocated: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReserveKind {
+    Liquidity,
+    Creator,
+    Burn,
 }
 
 #[account]
@@ -655,6 +4260,23 @@ pub struct MemeTokenState {
     pub created_at: i64,
     pub is_initialized: bool,
     pub bump: u8,
+    pub pool: Pubkey, // AmmPool for this mint (default until pool is created)
+    pub total_burned: u64, // holder burns + buybacks + vault reserve burn
+}
+
+impl MemeTokenState {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 1 + 1 + 32 + 8;
+    // Original layout, before `pool` and `total_burned` (see upgrade_meme_token_state)
+    pub const LEGACY_LEN: usize = 32 + 32 + 32 + 8 + 1 + 1;
+
+    // Returns the new cumulative total
+    fn record_burn(&mut self, amount: u64) -> Result<u64> {
+        self.total_burned = self
+            .total_burned
+            .checked_add(amount)
+            .ok_or(AmmError::MathOverflow)?;
+        Ok(self.total_burned)
+    }
 }
 
 #[account]
@@ -673,11 +4295,262 @@ pub struct AmmPool {
 
     pub bump: u8,
     pub is_initialized: bool,
+
+    // Graduation: real SOL raised that completes the curve (0 = sell-out only)
+    pub graduation_sol_threshold: u64,
+    pub is_complete: bool,
+    pub is_migrated: bool,
+
+    // Anti-snipe launch window (snapshotted from ProtocolState)
+    pub launch_slot: u64,
+    pub launch_window_slots: u64,
+    pub max_buy_per_wallet_lamports: u64,
+
+    // Price curve chosen at pool creation
+    pub curve_type: CurveType,
+
+    // Snapshotted from the LaunchConfig at creation
+    pub launch_config: Pubkey,
+    pub curve_tokens: u64,
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+
+    // Market data, refreshed on every swap
+    pub spot_price: u128, // lamports per token base unit, scaled by curve::WAD
+    pub market_cap_lamports: u64, // fully diluted (TOTAL_SUPPLY at spot price)
+    pub progress_bps: u16,        // bonding-curve progress towards graduation
+    pub buy_volume_lamports: u64,
+    pub sell_volume_lamports: u64,
+    pub trade_count: u64,
+
+    // Per-pool emergency halt (authority only)
+    pub halted: bool,
+
+    // Vault reserve still to be distributed (set from the LaunchConfig split)
+    pub liquidity_reserve_tokens: u64, // → cp pool at migration
+    pub creator_reserve_tokens: u64,   // → creator VestingAccount
+    pub burn_reserve_tokens: u64,      // → burned
+
+    // Creator vesting schedule (snapshotted from the LaunchConfig)
+    pub vesting_cliff_seconds: i64,
+    pub vesting_duration_seconds: i64,
 }
 
 impl AmmPool {
-    // Updated size: 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 = 130 bytes
-    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+    // Updated size: 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 1 + 1 + 8 + 8 + 8 + 17
+    //               + 32 + 8 + 2 + 2 + 16 + 8 + 2 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 8
+    //               = 316 bytes
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 1 + 1 + 8 + 8 + 8
+        + CurveType::LEN
+        + 32
+        + 8
+        + 2
+        + 2
+        + 16
+        + 8
+        + 2
+        + 8
+        + 8
+        + 8
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8;
+    // Original layout, up to `is_initialized` (see upgrade_amm_pool)
+    pub const LEGACY_LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    // Recompute spot price, market cap and progress from the reserves
+    pub fn refresh_market_data(&mut self) -> Result<()> {
+        self.spot_price = self.curve().spot_price()?;
+        // Display-only figure: saturate rather than block trades
+        let market_cap = self.spot_price.saturating_mul(TOTAL_SUPPLY as u128) / curve::WAD;
+        self.market_cap_lamports = market_cap.min(u64::MAX as u128) as u64;
+
+        // Graduation triggers on whichever of sell-out / SOL threshold comes first
+        let full = BPS_DENOMINATOR as u128;
+        let mut progress = if self.curve_tokens > 0 {
+            (self.tokens_sold() as u128) * full / (self.curve_tokens as u128)
+        } else {
+            0
+        };
+        if self.graduation_sol_threshold > 0 {
+            let sol_progress = (self.real_sol_reserve as u128) * full
+                / (self.graduation_sol_threshold as u128);
+            progress = progress.max(sol_progress);
+        }
+        if self.is_complete {
+            progress = full;
+        }
+        self.progress_bps = progress.min(full) as u16;
+
+        Ok(())
+    }
+
+    // Cumulative SOL volume (fees included) and trade count
+    fn record_trade(&mut self, sol_volume: u64, is_buy: bool) -> Result<()> {
+        if is_buy {
+            self.buy_volume_lamports = self
+                .buy_volume_lamports
+                .checked_add(sol_volume)
+                .ok_or(AmmError::MathOverflow)?;
+        } else {
+            self.sell_volume_lamports = self
+                .sell_volume_lamports
+                .checked_add(sol_volume)
+                .ok_or(AmmError::MathOverflow)?;
+        }
+        self.trade_count = self
+            .trade_count
+            .checked_add(1)
+            .ok_or(AmmError::MathOverflow)?;
+
+        self.refresh_market_data()
+    }
+
+    // Tokens sold off the curve so far
+    pub fn in_launch_window(&self, slot: u64) -> bool {
+        slot < self.launch_slot.saturating_add(self.launch_window_slots)
+    }
+
+    pub fn tokens_sold(&self) -> u64 {
+        self.curve_tokens.saturating_sub(self.real_token_reserve)
+    }
+
+    pub fn curve(&self) -> Box<dyn curve::BondingCurve> {
+        self.curve_type.curve(
+            self.virtual_sol_reserve,
+            self.virtual_token_reserve,
+            self.tokens_sold(),
+        )
+    }
+}
+
+// Price curve of a bonding-curve pool. Linear / exponential prices are
+// lamports per token base unit scaled by curve::WAD (see the curve module).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CurveType {
+    ConstantProduct,
+    Linear { start_price: u64, slope: u64 },
+    Exponential { start_price: u64, growth: u64 },
+}
+
+impl CurveType {
+    // 1 (variant) + largest payload (2 x u64)
+    pub const LEN: usize = 1 + 8 + 8;
+
+    pub fn curve(
+        &self,
+        virtual_sol: u64,
+        virtual_tokens: u64,
+        tokens_sold: u64,
+    ) -> Box<dyn curve::BondingCurve> {
+        match *self {
+            CurveType::ConstantProduct => Box::new(curve::ConstantProduct {
+                virtual_sol,
+                virtual_tokens,
+            }),
+            CurveType::Linear { start_price, slope } => Box::new(curve::Linear {
+                start_price,
+                slope,
+                tokens_sold,
+            }),
+            CurveType::Exponential { start_price, growth } => Box::new(curve::Exponential {
+                start_price,
+                growth,
+                tokens_sold,
+            }),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        match *self {
+            CurveType::ConstantProduct => {}
+            CurveType::Linear { start_price, slope } => {
+                require!(start_price > 0 && slope > 0, AmmError::InvalidCurveParams);
+            }
+            CurveType::Exponential { start_price, growth } => {
+                require!(start_price > 0 && growth > 0, AmmError::InvalidCurveParams);
+            }
+        }
+        Ok(())
+    }
+}
+
+// SOL a wallet has spent on one pool during its launch window
+#[account]
+pub struct UserLaunchBuys {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub sol_spent: u64,
+    pub bump: u8,
+}
+
+impl UserLaunchBuys {
+    pub const LEN: usize = 32 + 32 + 8 + 1;
+}
+
+// Holds creator fee lamports for one token until the minter claims them
+#[account]
+pub struct CreatorFeeVault {
+    pub token_mint: Pubkey,
+    pub total_accrued: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl CreatorFeeVault {
+    pub const LEN: usize = 32 + 8 + 8 + 1;
+}
+
+// Per-mint escrow ledger for the creator allocation
+#[account]
+pub struct VestingAccount {
+    pub token_mint: Pubkey,
+    pub beneficiary: Pubkey, // MemeTokenState.minter at funding time
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    pub bump: u8,
+    pub token_account_bump: u8,
+}
+
+impl VestingAccount {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    // Nothing before the cliff, then linear from start_ts to end_ts
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        ((self.total_amount as u128) * elapsed / duration) as u64
+    }
+}
+
+// Constant-product pool a graduated curve migrates into
+#[account]
+pub struct CpPool {
+    pub token_mint: Pubkey,
+    pub amm_pool: Pubkey,
+    pub sol_vault: Pubkey,
+    pub token_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub sol_reserve: u64,
+    pub token_reserve: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+impl CpPool {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
 }
 
 // ==================== EVENTS ====================
@@ -687,6 +4560,78 @@ pub struct ProtocolInitialized {
     pub authority: Pubkey,
     pub fee_vault: Pubkey,
     pub fee_lamports: u64,
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub graduation_sol_threshold: u64,
+    pub launch_window_slots: u64,
+    pub max_buy_per_wallet_lamports: u64,
+}
+
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub authority: Pubkey,
+    pub fee_lamports: u64,
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub graduation_sol_threshold: u64,
+    pub launch_window_slots: u64,
+    pub max_buy_per_wallet_lamports: u64,
+}
+
+#[event]
+pub struct LaunchConfigUpdated {
+    pub launch_config: Pubkey,
+    pub config_id: u16,
+    pub initial_sol: u64,
+    pub curve_tokens: u64,
+    pub virtual_sol_reserve: u64,
+    pub virtual_token_reserve: u64,
+    pub graduation_sol_threshold: u64,
+    pub trade_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub reserve_liquidity_bps: u16,
+    pub reserve_creator_bps: u16,
+    pub reserve_burn_bps: u16,
+    pub vesting_cliff_seconds: i64,
+    pub vesting_duration_seconds: i64,
+    pub curve_type: CurveType,
+}
+
+#[event]
+pub struct ProtocolPauseUpdated {
+    pub authority: Pubkey,
+    pub previous: u8,
+    pub paused: u8,
+    pub reason: u16,
+}
+
+#[event]
+pub struct PoolHaltUpdated {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub halted: bool,
+    pub reason: u16,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
 }
 
 #[event]
@@ -694,16 +4639,42 @@ pub struct Minted {
     pub meme_id: [u8; 32],
     pub minter: Pubkey,
     pub mint_addr: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct MemeLaunched {
+    pub meme_id: [u8; 32],
+    pub minter: Pubkey,
+    pub mint_addr: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub pool: Pubkey,
+    pub launch_config: Pubkey,
+    pub curve_type: CurveType,
+    pub real_sol: u64,
+    pub real_tokens: u64,
+    pub virtual_sol: u64,
+    pub virtual_tokens: u64,
+    pub initial_buy_sol: u64,
+    pub initial_buy_tokens: u64,
 }
 
 #[event]
 pub struct PoolInitialized {
     pub pool: Pubkey,
     pub token_mint: Pubkey,
+    pub launch_config: Pubkey,
+    pub curve_type: CurveType,
     pub real_sol: u64,
     pub real_tokens: u64,
     pub virtual_sol: u64,
     pub virtual_tokens: u64,
+    pub dev_buy_sol: u64,
+    pub dev_buy_tokens: u64,
 }
 
 #[event]
@@ -713,7 +4684,93 @@ pub struct SwapExecuted {
     pub input_token: String,
     pub input_amount: u64,
     pub output_amount: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct CurveCompleted {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub real_sol: u64,
+    pub real_tokens: u64,
+    pub virtual_sol: u64,
+    pub virtual_tokens: u64,
+}
+
+#[event]
+pub struct CpSwapExecuted {
+    pub cp_pool: Pubkey,
+    pub user: Pubkey,
+    pub is_buy: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
     pub fee: u64,
+    pub sol_reserve: u64,
+    pub token_reserve: u64,
+}
+
+#[event]
+pub struct ReserveDistributed {
+    pub token_mint: Pubkey,
+    pub kind: ReserveKind,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VestingFunded {
+    pub token_mint: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub token_mint: Pubkey,
+    pub minter: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct TokensBurned {
+    pub token_mint: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct BuybackBurned {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct PoolMigrated {
+    pub pool: Pubkey,
+    pub cp_pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+    pub lp_lock_account: Pubkey,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
 }
 
 // ==================== ERRORS ====================
@@ -722,9 +4779,50 @@ pub struct SwapExecuted {
 pub enum ErrorCode {
     #[msg("Meme already minted")]
     MemeAlreadyMinted,
+    #[msg("Token name too long")]
+    NameTooLong,
+    #[msg("Token symbol too long")]
+    SymbolTooLong,
+    #[msg("Token URI too long")]
+    UriTooLong,
+    #[msg("Signer is not the protocol authority")]
+    Unauthorized,
+    #[msg("Signer is not the pending protocol authority")]
+    NotPendingAuthority,
+    #[msg("Protocol state already initialized")]
+    ProtocolAlreadyInitialized,
+    #[msg("Signer is not the program upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("Withdraw amount must be greater than zero")]
+    InvalidWithdrawAmount,
+    #[msg("Fee vault balance too low (must stay rent-exempt)")]
+    InsufficientFeeVaultBalance,
+    #[msg("Fee exceeds the protocol maximum")]
+    FeeTooHigh,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Invalid launch config")]
+    InvalidLaunchConfig,
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+    #[msg("Minting is paused")]
+    MintingPaused,
+    #[msg("Pool creation is paused")]
+    PoolCreationPaused,
+    #[msg("Buys are paused")]
+    BuysPaused,
+    #[msg("Sells are paused")]
+    SellsPaused,
+    #[msg("Migration is paused")]
+    MigrationPaused,
+    #[msg("Nothing left to distribute")]
+    NothingToDistribute,
+    #[msg("Account is not in the legacy layout")]
+    NotLegacyAccount,
 }
 
-#[error_code]
+// Own range so AmmError codes never collide with ErrorCode's 6000+
+#[error_code(offset = 7000)]
 pub enum AmmError {
     #[msg("Invalid amount")]
     InvalidAmount,
@@ -738,4 +4836,26 @@ pub enum AmmError {
     MathOverflow,
     #[msg("Invalid vault")]
     InvalidVault,
+    #[msg("Mint does not match pool")]
+    InvalidMint,
+    #[msg("Mint was not created by this launchpad")]
+    MemeNotInitialized,
+    #[msg("Token account not owned by signer")]
+    InvalidTokenAccount,
+    #[msg("Bonding curve is complete - trading is closed")]
+    CurveComplete,
+    #[msg("Bonding curve is not complete yet")]
+    CurveNotComplete,
+    #[msg("Pool already migrated")]
+    AlreadyMigrated,
+    #[msg("Per-wallet buy limit exceeded during launch window")]
+    LaunchBuyLimitExceeded,
+    #[msg("Invalid curve parameters")]
+    InvalidCurveParams,
+    #[msg("Trading on this pool is halted")]
+    PoolHalted,
+    #[msg("Launch buy tracker required during the launch window")]
+    LaunchBuysAccountRequired,
+    #[msg("Launch window still active")]
+    LaunchWindowActive,
 }
@@ -0,0 +1,129 @@
+// Builds src/idl.json, in the legacy layout @coral-xyz/anchor 0.29 reads,
+// from the program's Anchor IDL build output:
+//
+//   cargo test __anchor_private_print_idl --features idl-build -- --show-output --quiet \
+//     | node scripts/idl-legacy.js > src/idl.json
+//
+// The raw sections are used because `anchor idl build` refuses programs
+// with more than one #[error_code] enum (ErrorCode + AmmError).
+
+const fs = require("fs");
+const path = require("path");
+
+const raw = fs.readFileSync(0, "utf8");
+const source = fs.readFileSync(path.join(__dirname, "..", "lib.rs"), "utf8");
+
+function sections(kind) {
+  const re = new RegExp(`--- IDL begin ${kind} ---\\n([\\s\\S]*?)--- IDL end ${kind} ---`, "g");
+  return [...raw.matchAll(re)].map((m) => JSON.parse(m[1]));
+}
+
+const [program] = sections("program");
+if (!program) {
+  throw new Error("no program section in the IDL build output");
+}
+
+// The IDL printer numbers every enum from 6000 and ignores
+// #[error_code(offset = N)], so the declared offsets are re-applied here
+const ANCHOR_ERROR_OFFSET = 6000;
+const snakeCase = (name) => name.replace(/([a-z0-9])([A-Z])/g, "$1_$2").toLowerCase();
+const errorOffsets = new Map(
+  [...source.matchAll(/#\[error_code\(offset = (\d+)\)\]\s*pub enum (\w+)/g)].map((m) => [
+    snakeCase(m[2]),
+    Number(m[1]),
+  ]),
+);
+
+function errors() {
+  const re =
+    /---- __anchor_private_print_idl_error_(\w+) stdout ----\n--- IDL begin errors ---\n([\s\S]*?)--- IDL end errors ---/g;
+  return [...raw.matchAll(re)].flatMap(([, enumName, body]) => {
+    const offset = errorOffsets.get(enumName) ?? ANCHOR_ERROR_OFFSET;
+    return JSON.parse(body).map((e) => ({ ...e, code: e.code - ANCHOR_ERROR_OFFSET + offset }));
+  });
+}
+
+const shortName = (name) => name.split("::").pop();
+const camelCase = (name) => name.replace(/_([a-z0-9])/g, (_, c) => c.toUpperCase());
+
+function convertType(type) {
+  if (typeof type === "string") return type === "pubkey" ? "publicKey" : type;
+  if (type.defined) return { defined: shortName(type.defined.name) };
+  if (type.option) return { option: convertType(type.option) };
+  if (type.vec) return { vec: convertType(type.vec) };
+  if (type.array) return { array: [convertType(type.array[0]), type.array[1]] };
+  throw new Error(`unsupported type ${JSON.stringify(type)}`);
+}
+
+const convertFields = (fields = []) =>
+  fields.map((f) => ({ name: camelCase(f.name), type: convertType(f.type) }));
+
+function convertTypeDef(def) {
+  const { kind } = def.type;
+  if (kind === "struct") {
+    return { name: shortName(def.name), type: { kind, fields: convertFields(def.type.fields) } };
+  }
+  const variants = def.type.variants.map((v) =>
+    v.fields ? { name: v.name, fields: convertFields(v.fields) } : { name: v.name },
+  );
+  return { name: shortName(def.name), type: { kind, variants } };
+}
+
+function convertInstruction(ix) {
+  const out = {
+    name: camelCase(ix.name),
+    accounts: ix.accounts.map((a) => ({
+      name: camelCase(a.name),
+      isMut: !!a.writable,
+      isSigner: !!a.signer,
+      ...(a.optional ? { isOptional: true } : {}),
+    })),
+    args: ix.args.map((a) => ({ name: camelCase(a.name), type: convertType(a.type) })),
+  };
+  if (ix.returns) out.returns = convertType(ix.returns);
+  return out;
+}
+
+// Types referenced by events are only printed in the event sections
+const typeDefs = new Map(program.types.map((t) => [t.name, t]));
+const events = sections("event");
+for (const { types } of events) {
+  for (const t of types) typeDefs.set(t.name, t);
+}
+
+const accountNames = new Set(program.accounts.map((a) => a.name));
+const eventNames = new Set(events.map((e) => e.event.name));
+
+const idl = {
+  version: "0.1.0",
+  name: "meme_launchpad",
+  instructions: program.instructions.map(convertInstruction),
+  accounts: program.accounts.map((a) => convertTypeDef(typeDefs.get(a.name))),
+  types: [...typeDefs.values()]
+    .filter((t) => !accountNames.has(t.name) && !eventNames.has(t.name))
+    .map(convertTypeDef),
+  events: events.map(({ event }) => ({
+    name: shortName(event.name),
+    fields: typeDefs
+      .get(event.name)
+      .type.fields.map((f) => ({ name: camelCase(f.name), type: convertType(f.type), index: false })),
+  })),
+  errors: errors().sort((a, b) => a.code - b.code),
+};
+
+// Anchor TS builds every coder up front, so an unresolved type breaks startup
+const known = new Set([...idl.accounts, ...idl.types].map((t) => t.name));
+const missing = new Set();
+JSON.stringify(idl, (key, value) => {
+  if (key === "defined" && !known.has(value)) missing.add(value);
+  return value;
+});
+if (missing.size > 0) {
+  throw new Error(`unresolved types: ${[...missing].join(", ")}`);
+}
+const codes = idl.errors.map((e) => e.code);
+if (codes.length !== sections("errors").flat().length || new Set(codes).size !== codes.length) {
+  throw new Error("duplicate error codes");
+}
+
+process.stdout.write(JSON.stringify(idl));
@@ -10,6 +10,7 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { getProgram } from "./service";
+import { getProtocolStatePda, getFeeVaultPda } from "./helpers";
 
 // ⚠️ UPDATED: Removed lpMint and lpSupply - bonding curve model doesn't use LP tokens
 interface AmmPool {
@@ -28,6 +29,9 @@ const AMM_POOL_SEED = "amm_pool";
 // ❌ REMOVED: LP_MINT_SEED - bonding curve model doesn't use LP tokens
 const POOL_SOL_VAULT_SEED = "pool_sol_vault";
 const POOL_TOKEN_VAULT_SEED = "pool_token_vault";
+const CREATOR_FEE_VAULT_SEED = "creator_fee_vault";
+const LAUNCH_CONFIG_SEED = "launch_config";
+const LAUNCH_BUY_SEED = "launch_buy";
 
 // Helper: Derive AMM Pool PDA
 export function getAmmPoolPda(tokenMint: PublicKey, programId: PublicKey): [PublicKey, number] {
@@ -62,17 +66,63 @@ export function getTokenVaultPda(tokenMint: PublicKey, programId: PublicKey): [P
   return [pda, bump];
 }
 
+// Helper: Derive Creator Fee Vault PDA
+export function getCreatorFeeVaultPda(tokenMint: PublicKey, programId: PublicKey): [PublicKey, number] {
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(CREATOR_FEE_VAULT_SEED), tokenMint.toBuffer()],
+    programId
+  );
+  return [pda, bump];
+}
+
+// Helper: Derive Launch Config PDA (u16 config id, little-endian)
+export function getLaunchConfigPda(configId: number, programId: PublicKey): [PublicKey, number] {
+  const configIdBytes = Buffer.alloc(2);
+  configIdBytes.writeUInt16LE(configId);
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(LAUNCH_CONFIG_SEED), configIdBytes],
+    programId
+  );
+  return [pda, bump];
+}
+
+// Helper: Derive a wallet's launch-window buy tracker for a pool
+export function getLaunchBuysPda(pool: PublicKey, user: PublicKey, programId: PublicKey): [PublicKey, number] {
+  const [pda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(LAUNCH_BUY_SEED), pool.toBuffer(), user.toBuffer()],
+    programId
+  );
+  return [pda, bump];
+}
+
+// Helper: Find the MemeTokenState PDA for a mint (it is seeded by meme_id, not the mint)
+export async function findMemeTokenStateForMint(
+  program: anchor.Program,
+  tokenMint: PublicKey,
+): Promise<PublicKey> {
+  const states = await program.account.memeTokenState.all([
+    // discriminator (8) + meme_id (32)
+    { memcmp: { offset: 8 + 32, bytes: tokenMint.toBase58() } },
+  ]);
+  if (states.length === 0) {
+    throw new Error(`Mint ${tokenMint.toBase58()} was not created by this launchpad`);
+  }
+  return states[0].publicKey;
+}
+
 /**
  * Initialize a new AMM pool
- * ⚠️ UPDATED: No longer accepts parameters - uses FIXED values from Rust program
- * Fixed: 0.02 SOL + 800M tokens (pump.fun style)
+ * ⚠️ UPDATED: Parameters come from a LaunchConfig preset (config #0 by default)
+ * Curve from the config, no dev buy
+ * Only the meme's minter may create its pool, so this works for tokens minted by the admin wallet
  */
 export async function initializeAmmPool(
   tokenMintAddress: string,
+  configId: number = 0,
 ) {
   console.log("🏊 [initializeAmmPool] Starting pool initialization...");
   console.log("   Token mint:", tokenMintAddress);
-  console.log("   Using FIXED parameters: 0.02 SOL + 800M tokens");
+  console.log("   Using launch config #" + configId);
 
   const { program, adminKeypair } = getProgram();
   const tokenMint = new PublicKey(tokenMintAddress);
@@ -81,6 +131,10 @@ export async function initializeAmmPool(
   const [poolPda] = getAmmPoolPda(tokenMint, program.programId);
   const [solVaultPda] = getSolVaultPda(tokenMint, program.programId);
   const [tokenVaultPda] = getTokenVaultPda(tokenMint, program.programId);
+  const [creatorFeeVaultPda] = getCreatorFeeVaultPda(tokenMint, program.programId);
+  const [launchConfigPda] = getLaunchConfigPda(configId, program.programId);
+  const [protocolState] = getProtocolStatePda(program.programId);
+  const [feeVault] = getFeeVaultPda(program.programId);
 
   // Derive minting vault PDAs
   console.log("🔑 [initializeAmmPool] Deriving minting vault PDAs...");
@@ -99,6 +153,13 @@ export async function initializeAmmPool(
   );
   console.log("   Vault Token Account:", vaultTokenAccount.toBase58());
 
+  const initializerTokenAccount = getAssociatedTokenAddressSync(
+    tokenMint,
+    adminKeypair.publicKey,
+    false,
+    TOKEN_2022_PROGRAM_ID
+  );
+
   console.log("🏊 [initializeAmmPool] Account summary:");
   console.log("   Pool PDA:", poolPda.toBase58());
   console.log("   SOL Vault PDA:", solVaultPda.toBase58());
@@ -109,17 +170,25 @@ export async function initializeAmmPool(
   try {
     console.log("🏊 [initializeAmmPool] Sending transaction...");
 
-    // ⚠️ IMPORTANT: initializeAmmPool() takes NO parameters
+    const memeTokenState = await findMemeTokenStateForMint(program, tokenMint);
+
+    // No dev buy
     const tx = await program.methods
-      .initializeAmmPool()
+      .initializeAmmPool(new anchor.BN(0), new anchor.BN(0))
       .accounts({
         initializer: adminKeypair.publicKey,
         tokenMint,
+        memeTokenState,
         pool: poolPda,
         solVault: solVaultPda,
         tokenVault: tokenVaultPda,
+        creatorFeeVault: creatorFeeVaultPda,
+        protocolState,
+        launchConfig: launchConfigPda,
         vault: vaultPda,
         vaultTokenAccount: vaultTokenAccount,
+        feeVault,
+        initializerTokenAccount,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -136,8 +205,7 @@ export async function initializeAmmPool(
       solVault: solVaultPda.toBase58(),
       tokenVault: tokenVaultPda.toBase58(),
       vaultUsed: vaultPda.toBase58(),
-      initialSol: 0.02, // Fixed value
-      initialTokens: "800000000", // Fixed value
+      launchConfig: launchConfigPda.toBase58(),
     };
   } catch (error: any) {
     console.error("❌ [initializeAmmPool] Error:", error);
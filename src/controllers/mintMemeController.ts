@@ -51,9 +51,17 @@ export async function checkProtocolStateController(req: Request, res: Response)
 
 export async function mintMemeTxController(req: Request, res: Response) {
   try {
-    const { minter, memeId } = req.body as { minter: string; memeId: string };
-    if (!minter || !memeId) {
-      return res.status(400).json({ success: false, message: "minter and memeId are required" });
+    const { minter, memeId, name, symbol, uri } = req.body as {
+      minter: string;
+      memeId: string;
+      name: string;
+      symbol: string;
+      uri: string;
+    };
+    if (!minter || !memeId || !name || !symbol || !uri) {
+      return res
+        .status(400)
+        .json({ success: false, message: "minter, memeId, name, symbol and uri are required" });
     }
 
     let minterPubkey: PublicKey;
@@ -64,7 +72,13 @@ export async function mintMemeTxController(req: Request, res: Response) {
     }
 
     const memeIdBuffer = stringToMemeId(memeId);
-    const result = await createMintMemeTokenTransaction(minterPubkey, memeIdBuffer);
+    const result = await createMintMemeTokenTransaction(
+      minterPubkey,
+      memeIdBuffer,
+      name,
+      symbol,
+      uri,
+    );
     return res.status(result.success ? 200 : 500).json(result);
   } catch (error: any) {
     return res.status(500).json({ success: false, message: error?.message || String(error) });
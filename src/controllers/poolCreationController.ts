@@ -10,9 +10,12 @@ import { getAmmPool } from "../mintDetails";
 
 export async function initializeAmmPoolTxController(req: Request, res: Response) {
   try {
-    const { initializer, tokenMint } = req.body as {
+    const { initializer, tokenMint, configId, devBuySol, minDevBuyTokens } = req.body as {
       initializer: string;
       tokenMint: string;
+      configId?: number;
+      devBuySol?: number;
+      minDevBuyTokens?: number;
     };
 
     if (!initializer || !tokenMint) {
@@ -26,9 +29,15 @@ export async function initializeAmmPoolTxController(req: Request, res: Response)
       return res.status(400).json({ success: false, message: "Invalid initializer public key" });
     }
 
-    // ⚠️ UPDATED: No longer accepts initialSolAmount and initialTokenAmount
-    // The Rust program uses FIXED parameters: 0.02 SOL + 800M tokens
-    const result = await createInitializeAmmPoolTransaction(initializerPk, tokenMint);
+    // ⚠️ UPDATED: Pool parameters and curve come from the LaunchConfig preset (configId, default 0)
+    // devBuySol/minDevBuyTokens are optional (default 0 = no dev buy)
+    const result = await createInitializeAmmPoolTransaction(
+      initializerPk,
+      tokenMint,
+      configId ?? 0,
+      devBuySol ?? 0,
+      minDevBuyTokens ?? 0,
+    );
     return res.status(result.success ? 200 : 500).json(result);
   } catch (error: any) {
     return res.status(500).json({ success: false, message: error?.message || String(error) });
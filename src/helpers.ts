@@ -6,6 +6,7 @@ export const MEME_TOKEN_STATE_SEED = "meme_token_state";
 export const PROTOCOL_STATE_SEED = "protocol_state_v2";
 export const FEE_VAULT_SEED = "fee_vault";
 export const VAULT_SEED = "vault";
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
 
 // === Helper: Generate random meme_id ===
 export function generateMemeId(): Buffer {
@@ -21,6 +22,14 @@ export function getProtocolStatePda(programId: PublicKey): [PublicKey, number] {
   return result;
 }
 
+// === Helper: Derive the program's ProgramData account (holds the upgrade authority) ===
+export function getProgramDataPda(programId: PublicKey): [PublicKey, number] {
+  const result = PublicKey.findProgramAddressSync([
+    programId.toBuffer(),
+  ], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+  return result;
+}
+
 // === Helper: Derive fee vault PDA ===
 export function getFeeVaultPda(programId: PublicKey): [PublicKey, number] {
   const result = PublicKey.findProgramAddressSync([
@@ -37,6 +37,7 @@ export {
 import {
   getProtocolStatePda,
   getFeeVaultPda,
+  getProgramDataPda,
   getMemeTokenStatePda,
   getVaultPda,
   lamportsToSol,
@@ -99,13 +100,14 @@ export async function getNativeSolBalance(publicKey: PublicKey): Promise<number>
 }
 
 // === Initialize Protocol State ===
-export async function initializeProtocolState(feeLamports: number) {
-  console.log("🚀 [initializeProtocolState] Starting protocol initialization with fee:", feeLamports, "lamports");
+export async function initializeProtocolState() {
+  console.log("🚀 [initializeProtocolState] Starting protocol initialization...");
   
   const { program, adminKeypair } = getProgram();
 
   const [protocolState] = getProtocolStatePda(program.programId);
   const [feeVault] = getFeeVaultPda(program.programId);
+  const [programData] = getProgramDataPda(program.programId);
   
   console.log("🚀 [initializeProtocolState] Protocol state PDA:", protocolState.toBase58());
   console.log("🚀 [initializeProtocolState] Fee vault PDA:", feeVault.toBase58());
@@ -114,11 +116,13 @@ export async function initializeProtocolState(feeLamports: number) {
   try {
     console.log("🚀 [initializeProtocolState] Sending initialize transaction...");
     const tx = await program.methods
-      .initializeProtocolState(new anchor.BN(feeLamports))
+      .initializeProtocolState()
       .accounts({
         protocolState: protocolState,
         authority: adminKeypair.publicKey,
         feeVault: feeVault,
+        program: program.programId,
+        programData: programData,
         systemProgram: SystemProgram.programId,
       })
       .signers([adminKeypair])
@@ -131,7 +135,6 @@ export async function initializeProtocolState(feeLamports: number) {
       adminPublicKey: adminKeypair.publicKey,
       protocolState: protocolState,
       feeVault: feeVault,
-      feeLamports: feeLamports,
     };
   } catch (error) {
     console.error("❌ [initializeProtocolState] Error initializing protocol:", error);
@@ -174,9 +177,11 @@ export async function resetProtocolState() {
 }
 
 // === Mint Meme Token 
-export async function mintMemeToken(memeId: Buffer) {
+// name/symbol/uri are written to the mint's Token-2022 metadata extension
+export async function mintMemeToken(memeId: Buffer, name: string, symbol: string, uri: string) {
   console.log("🎭 [mintMemeToken] Starting meme token minting process...");
   console.log("🎭 [mintMemeToken] Input meme ID:", memeId.toString('hex'));
+  console.log("🎭 [mintMemeToken] Metadata:", { name, symbol, uri });
   
   const finalMemeId = memeId;
   const { program, adminKeypair } = getProgram();
@@ -224,7 +229,7 @@ export async function mintMemeToken(memeId: Buffer) {
   try {
     console.log("🎭 [mintMemeToken] Step 3: Sending mint transaction...");
     const tx = await program.methods
-      .mintMemeToken(Array.from(finalMemeId))
+      .mintMemeToken(Array.from(finalMemeId), name, symbol, uri)
       .accounts({
         minter: adminKeypair.publicKey,
         memeTokenState,
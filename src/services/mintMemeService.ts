@@ -15,6 +15,7 @@ import {
 import {
   getProtocolStatePda,
   getFeeVaultPda,
+  getProgramDataPda,
   getMemeTokenStatePda,
   getVaultPda,
 } from "../helpers";
@@ -65,6 +66,7 @@ export async function createInitializeProtocolStateTransaction(
 
   const [protocolState] = getProtocolStatePda(program.programId);
   const [feeVault] = getFeeVaultPda(program.programId);
+  const [programData] = getProgramDataPda(program.programId);
 
   try {
     const { blockhash } = await connection.getLatestBlockhash("finalized");
@@ -75,6 +77,8 @@ export async function createInitializeProtocolStateTransaction(
         protocolState: protocolState,
         authority: adminPublicKey,
         feeVault: feeVault,
+        program: program.programId,
+        programData: programData,
         systemProgram: SystemProgram.programId,
       })
       .transaction();
@@ -109,7 +113,14 @@ export async function createInitializeProtocolStateTransaction(
 
 // === Mint Meme Token (unsigned) ===
 // ✅ UNCHANGED: Still costs 0.01 SOL, but now it's enforced by the contract
-export async function createMintMemeTokenTransaction(minterPublicKey: PublicKey, memeId: Buffer) {
+// name/symbol/uri are written to the mint's Token-2022 metadata extension
+export async function createMintMemeTokenTransaction(
+  minterPublicKey: PublicKey,
+  memeId: Buffer,
+  name: string,
+  symbol: string,
+  uri: string,
+) {
   const { program, connection } = getProgram();
 
   const finalMemeId = memeId;
@@ -124,13 +135,7 @@ export async function createMintMemeTokenTransaction(minterPublicKey: PublicKey,
   const [vault] = getVaultPda(mintPDA, program.programId);
   const [feeVault] = getFeeVaultPda(program.programId);
 
-  // Token-2022 ATAs
-  const minterTokenAccount = getAssociatedTokenAddressSync(
-    mintPDA,
-    minterPublicKey,
-    false,
-    TOKEN_2022_PROGRAM_ID,
-  );
+  // Token-2022 ATA holding the full supply
   const vaultTokenAccount = getAssociatedTokenAddressSync(
     mintPDA,
     vault,
@@ -142,13 +147,12 @@ export async function createMintMemeTokenTransaction(minterPublicKey: PublicKey,
     const { blockhash } = await connection.getLatestBlockhash("finalized");
 
     const transaction = await program.methods
-      .mintMemeToken(Array.from(finalMemeId))
+      .mintMemeToken(Array.from(finalMemeId), name, symbol, uri)
       .accounts({
         minter: minterPublicKey,
         memeTokenState,
         mint: mintPDA,
         vault,
-        minterTokenAccount,
         vaultTokenAccount,
         feeVault,
         protocolState,
@@ -171,12 +175,14 @@ export async function createMintMemeTokenTransaction(minterPublicKey: PublicKey,
         minter: minterPublicKey.toString(),
         memeTokenState: memeTokenState.toString(),
         vault: vault.toString(),
-        minterTokenAccount: minterTokenAccount.toString(),
         vaultTokenAccount: vaultTokenAccount.toString(),
         feeVault: feeVault.toString(),
         protocolState: protocolState.toString(),
       },
       metadata: {
+        name,
+        symbol,
+        uri,
         fee: "0.01 SOL (10,000,000 lamports)",
         totalSupply: "1,000,000,000 tokens",
         distribution: {
@@ -17,7 +17,12 @@ import {
   getAmmPoolPda,
   getSolVaultPda,
   getTokenVaultPda,
+  getCreatorFeeVaultPda,
+  getLaunchConfigPda,
+  getLaunchBuysPda,
+  findMemeTokenStateForMint,
 } from "../ammService";
+import { getProtocolStatePda, getFeeVaultPda } from "../helpers";
 
 // ==================== HELPER FUNCTIONS ====================
 
@@ -124,23 +129,27 @@ export function calculateSellOutput(
 
 /**
  * Initialize AMM Pool (creates bonding curve for trading)
- * ⚠️ UPDATED: Fixed parameters (0.02 SOL + 800M tokens) - no parameters needed!
- * The Rust program uses FIXED_INITIAL_SOL (0.02 SOL) and FIXED_INITIAL_TOKENS (800M tokens)
+ * ⚠️ UPDATED: Pool parameters, including the price curve, come from a LaunchConfig preset (config #0 by default)
+ * Only the meme's minter can initialize its pool
+ * Optionally makes a dev buy in the same transaction (devBuySol = 0 skips it)
  */
 export async function createInitializeAmmPoolTransaction(
   initializerPublicKey: PublicKey,
   tokenMintAddress: string,
+  configId: number = 0,
+  devBuySol: number = 0, // in SOL (e.g., 0.1)
+  minDevBuyTokens: number = 0, // in human-readable tokens (slippage protection)
 ) {
   const { program, connection } = getProgram();
   const tokenMint = new PublicKey(tokenMintAddress);
 
-  // Fixed constants from Rust program
-  const FIXED_INITIAL_SOL = 20_000_000; // 0.02 SOL in lamports
-  const FIXED_INITIAL_TOKENS = 800_000_000_000_000_000; // 800M tokens in base units
-
   const [poolPda] = getAmmPoolPda(tokenMint, program.programId);
   const [solVaultPda] = getSolVaultPda(tokenMint, program.programId);
   const [tokenVaultPda] = getTokenVaultPda(tokenMint, program.programId);
+  const [creatorFeeVaultPda] = getCreatorFeeVaultPda(tokenMint, program.programId);
+  const [launchConfigPda] = getLaunchConfigPda(configId, program.programId);
+  const [protocolState] = getProtocolStatePda(program.programId);
+  const [feeVault] = getFeeVaultPda(program.programId);
 
   // Minting vault PDA
   const [vaultPda] = PublicKey.findProgramAddressSync(
@@ -155,20 +164,39 @@ export async function createInitializeAmmPoolTransaction(
     TOKEN_2022_PROGRAM_ID,
   );
 
+  // Receives the optional dev buy (created by the program if missing)
+  const initializerTokenAccount = getAssociatedTokenAddressSync(
+    tokenMint,
+    initializerPublicKey,
+    false,
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const devBuyLamports = new anchor.BN(Math.floor(devBuySol * LAMPORTS_PER_SOL));
+  const minDevBuyBaseUnits = new anchor.BN(toBaseUnits(minDevBuyTokens, 9));
+
   try {
+    const memeTokenState = await findMemeTokenStateForMint(program, tokenMint);
+    const launchConfig = (await program.account.launchConfig.fetch(launchConfigPda)) as any;
+
     const { blockhash } = await connection.getLatestBlockhash("finalized");
 
-    // ⚠️ IMPORTANT: initializeAmmPool() takes NO parameters - it uses fixed values from the contract
     const transaction = await program.methods
-      .initializeAmmPool()
+      .initializeAmmPool(devBuyLamports, minDevBuyBaseUnits)
       .accounts({
         initializer: initializerPublicKey,
         tokenMint,
+        memeTokenState,
         pool: poolPda,
         solVault: solVaultPda,
         tokenVault: tokenVaultPda,
+        creatorFeeVault: creatorFeeVaultPda,
+        protocolState,
+        launchConfig: launchConfigPda,
         vault: vaultPda,
         vaultTokenAccount: vaultTokenAccount,
+        feeVault,
+        initializerTokenAccount,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -178,11 +206,11 @@ export async function createInitializeAmmPoolTransaction(
     transaction.feePayer = initializerPublicKey;
     transaction.recentBlockhash = blockhash;
 
-    // Calculate initial price using virtual reserves (for display purposes)
-    const INITIAL_VIRTUAL_SOL_RESERVES = 30_000_000_000; // 30 SOL
-    const INITIAL_VIRTUAL_TOKEN_RESERVES = 1_073_000_000_000_000_000; // 1.073B tokens
-    const virtualSolReserve = INITIAL_VIRTUAL_SOL_RESERVES + FIXED_INITIAL_SOL;
-    const initialPrice = virtualSolReserve / (Number(INITIAL_VIRTUAL_TOKEN_RESERVES) / 1e9);
+    // Starting spot price of the constant-product curve (for display purposes)
+    const initialSol = Number(launchConfig.initialSol.toString());
+    const virtualSolReserve = Number(launchConfig.virtualSolReserve.toString()) + initialSol;
+    const virtualTokenReserve = Number(launchConfig.virtualTokenReserve.toString());
+    const initialPrice = virtualSolReserve / (virtualTokenReserve / 1e9);
 
     return {
       success: true,
@@ -197,12 +225,21 @@ export async function createInitializeAmmPoolTransaction(
         vault: vaultPda.toString(),
         vaultTokenAccount: vaultTokenAccount.toString(),
         tokenMint: tokenMint.toString(),
+        memeTokenState: memeTokenState.toString(),
+        creatorFeeVault: creatorFeeVaultPda.toString(),
+        launchConfig: launchConfigPda.toString(),
+        initializerTokenAccount: initializerTokenAccount.toString(),
       },
       metadata: {
-        initialSolAmount: `0.02 SOL (${FIXED_INITIAL_SOL} lamports) - FIXED`,
-        initialTokenAmount: `800,000,000 tokens (${FIXED_INITIAL_TOKENS} base units) - FIXED`,
-        initialPrice: `${initialPrice.toExponential(8)} SOL per token (using virtual reserves)`,
-        note: "Pool uses fixed parameters (pump.fun style). No LP tokens issued - this is a bonding curve.",
+        launchConfig: `#${configId}`,
+        curveType: Object.keys(launchConfig.curveType)[0],
+        initialSolAmount: `${initialSol / LAMPORTS_PER_SOL} SOL (${initialSol} lamports)`,
+        initialTokenAmount: `${launchConfig.curveTokens.toString()} base units`,
+        initialPrice: `${initialPrice.toExponential(8)} SOL per token (constant product, using virtual reserves)`,
+        devBuy: devBuySol > 0
+          ? `${devBuySol} SOL (${devBuyLamports.toString()} lamports)`
+          : "None",
+        note: "Pool parameters are snapshotted from the launch config. No LP tokens issued - this is a bonding curve.",
       },
     };
   } catch (error: any) {
@@ -243,6 +280,10 @@ export async function createSwapSolForTokensTransaction(
   const [poolPda] = getAmmPoolPda(tokenMint, program.programId);
   const [solVaultPda] = getSolVaultPda(tokenMint, program.programId);
   const [tokenVaultPda] = getTokenVaultPda(tokenMint, program.programId);
+  const [creatorFeeVaultPda] = getCreatorFeeVaultPda(tokenMint, program.programId);
+  const [protocolState] = getProtocolStatePda(program.programId);
+  const [feeVault] = getFeeVaultPda(program.programId);
+  const [launchBuysPda] = getLaunchBuysPda(poolPda, userPublicKey, program.programId);
 
   // Get user's token account address
   const userTokenAccount = await getAssociatedTokenAddress(
@@ -282,6 +323,12 @@ export async function createSwapSolForTokensTransaction(
   try {
     const { blockhash } = await connection.getLatestBlockhash("finalized");
 
+    // The per-wallet buy tracker is only required during the anti-snipe launch window
+    const pool = (await program.account.ammPool.fetch(poolPda)) as any;
+    const slot = await connection.getSlot("confirmed");
+    const launchWindowEnd = pool.launchSlot.add(pool.launchWindowSlots);
+    const inLaunchWindow = new anchor.BN(slot).lt(launchWindowEnd);
+
     // Create swap instruction
     const swapIx = await program.methods
       .swapSolToTokens(solLamports, minTokensBaseUnits)
@@ -290,8 +337,12 @@ export async function createSwapSolForTokensTransaction(
         pool: poolPda,
         tokenMint,
         solVault: solVaultPda,
+        feeVault,
+        protocolState,
+        creatorFeeVault: creatorFeeVaultPda,
         tokenVault: tokenVaultPda,
         userTokenAccount,
+        launchBuys: inLaunchWindow ? launchBuysPda : null,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -318,6 +369,7 @@ export async function createSwapSolForTokensTransaction(
         solVault: solVaultPda.toString(),
         tokenVault: tokenVaultPda.toString(),
         userTokenAccount: userTokenAccount.toString(),
+        launchBuys: inLaunchWindow ? launchBuysPda.toString() : null,
       },
       metadata: {
         solAmount: `${solAmount} SOL (${solLamports.toString()} lamports)`,
@@ -325,8 +377,9 @@ export async function createSwapSolForTokensTransaction(
           ? `${minTokenAmount} tokens (${minTokensBaseUnits.toString()} base units)`
           : "No minimum (pump.fun mode)",
         slippageProtection: minTokenAmount ? "ENABLED" : "DISABLED",
-        fee: "0.3% of SOL input",
+        fee: `${(pool.tradeFeeBps + pool.creatorFeeBps) / 100}% of SOL input`,
         action: "BUY",
+        launchWindow: inLaunchWindow ? "ACTIVE (per-wallet buy cap applies)" : "ENDED",
         accountCreated: !accountInfo,
       },
       message: !accountInfo
@@ -366,6 +419,9 @@ export async function createSwapTokensForSolTransaction(
   const [poolPda] = getAmmPoolPda(tokenMint, program.programId);
   const [solVaultPda] = getSolVaultPda(tokenMint, program.programId);
   const [tokenVaultPda] = getTokenVaultPda(tokenMint, program.programId);
+  const [creatorFeeVaultPda] = getCreatorFeeVaultPda(tokenMint, program.programId);
+  const [protocolState] = getProtocolStatePda(program.programId);
+  const [feeVault] = getFeeVaultPda(program.programId);
 
   // Get user's token account
   const userTokenAccount = await getAssociatedTokenAddress(
@@ -394,6 +450,9 @@ export async function createSwapTokensForSolTransaction(
         pool: poolPda,
         tokenMint,
         solVault: solVaultPda,
+        feeVault,
+        protocolState,
+        creatorFeeVault: creatorFeeVaultPda,
         tokenVault: tokenVaultPda,
         userTokenAccount,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
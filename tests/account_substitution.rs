@@ -0,0 +1,318 @@
+// Every instruction that moves pool funds must reject accounts belonging
+// to another meme token's pool.
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use common::*;
+use meme_launchpad::{accounts, AmmError, AmmPool, CpPool};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn swaps_accept_the_pool_accounts() {
+    let (mut launchpad, meme, _) = two_pools().await;
+    let user_token_account = ata(&launchpad.user.pubkey(), &meme.mint);
+
+    let accounts = launchpad.buy_accounts(&meme);
+    launchpad
+        .buy(accounts, LAMPORTS_PER_SOL / 10)
+        .await
+        .unwrap();
+    let bought = launchpad.token_balance(&user_token_account).await;
+    assert!(bought > 0);
+
+    let accounts = launchpad.sell_accounts(&meme);
+    launchpad.sell(accounts, bought).await.unwrap();
+    assert_eq!(launchpad.token_balance(&user_token_account).await, 0);
+}
+
+#[tokio::test]
+async fn buy_rejects_foreign_accounts() {
+    let (mut launchpad, meme, other) = two_pools().await;
+    let amount = LAMPORTS_PER_SOL / 10;
+
+    let accounts = accounts::SwapSolForTokens {
+        token_mint: other.mint,
+        ..launchpad.buy_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.buy(accounts, amount).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+
+    let accounts = accounts::SwapSolForTokens {
+        sol_vault: other.sol_vault,
+        ..launchpad.buy_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.buy(accounts, amount).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    let accounts = accounts::SwapSolForTokens {
+        token_vault: other.token_vault,
+        ..launchpad.buy_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.buy(accounts, amount).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    let accounts = accounts::SwapSolForTokens {
+        user_token_account: ata(&launchpad.user.pubkey(), &other.mint),
+        ..launchpad.buy_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.buy(accounts, amount).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+}
+
+#[tokio::test]
+async fn sell_rejects_foreign_accounts() {
+    let (mut launchpad, meme, other) = two_pools().await;
+    let accounts = launchpad.buy_accounts(&meme);
+    launchpad
+        .buy(accounts, LAMPORTS_PER_SOL / 10)
+        .await
+        .unwrap();
+    let amount = launchpad
+        .token_balance(&ata(&launchpad.user.pubkey(), &meme.mint))
+        .await;
+
+    let accounts = accounts::SwapTokensForSol {
+        token_mint: other.mint,
+        ..launchpad.sell_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.sell(accounts, amount).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+
+    let accounts = accounts::SwapTokensForSol {
+        sol_vault: other.sol_vault,
+        ..launchpad.sell_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.sell(accounts, amount).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    let accounts = accounts::SwapTokensForSol {
+        token_vault: other.token_vault,
+        ..launchpad.sell_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.sell(accounts, amount).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    let accounts = accounts::SwapTokensForSol {
+        user_token_account: ata(&launchpad.user.pubkey(), &other.mint),
+        ..launchpad.sell_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.sell(accounts, amount).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+}
+
+#[tokio::test]
+async fn initialize_pool_rejects_foreign_accounts() {
+    let mut launchpad = Launchpad::new().await;
+    let meme = launchpad.mint_meme(1).await;
+    let other = launchpad.mint_meme(2).await;
+
+    // A launchpad mint paired with another meme's state
+    let accounts = accounts::InitializePool {
+        meme_token_state: meme.meme_token_state,
+        ..launchpad.initialize_pool_accounts(&other)
+    };
+    assert_eq!(
+        launchpad.initialize_pool(accounts).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+
+    // Another meme's supply vault
+    let accounts = accounts::InitializePool {
+        vault_token_account: other.vault_token_account,
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.initialize_pool(accounts).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+
+    // Right mint, but not the vault's token account
+    let user = launchpad.user.pubkey();
+    let decoy = launchpad.create_token_account(&user, &meme.mint).await;
+    let accounts = accounts::InitializePool {
+        vault_token_account: decoy,
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.initialize_pool(accounts).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    // The pool vaults are PDAs of the mint, so Anchor's seeds check
+    // rejects them before any AmmError constraint runs
+    let seeds_error = anchor_error(AnchorErrorCode::ConstraintSeeds as u32);
+    let accounts = accounts::InitializePool {
+        sol_vault: other.sol_vault,
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    assert_eq!(launchpad.initialize_pool(accounts).await, seeds_error);
+
+    let accounts = accounts::InitializePool {
+        token_vault: other.token_vault,
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    assert_eq!(launchpad.initialize_pool(accounts).await, seeds_error);
+
+    // Nothing above left a pool behind
+    let accounts = launchpad.initialize_pool_accounts(&meme);
+    launchpad.initialize_pool(accounts).await.unwrap();
+    let pool: AmmPool = launchpad.anchor_account(&meme.pool).await;
+    assert_eq!(pool.token_mint, meme.mint);
+    assert_eq!(pool.token_vault, meme.token_vault);
+}
+
+#[tokio::test]
+async fn migrate_pool_rejects_foreign_accounts() {
+    let (mut launchpad, meme, other) = two_graduated_pools().await;
+
+    // The curve pool is a PDA of the mint
+    let accounts = accounts::MigratePool {
+        token_mint: other.mint,
+        ..launchpad.migrate_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.migrate(accounts).await,
+        anchor_error(AnchorErrorCode::ConstraintSeeds as u32)
+    );
+
+    let accounts = accounts::MigratePool {
+        sol_vault: other.sol_vault,
+        ..launchpad.migrate_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.migrate(accounts).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    let accounts = accounts::MigratePool {
+        token_vault: other.token_vault,
+        ..launchpad.migrate_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.migrate(accounts).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    // Another meme's liquidity reserve
+    let accounts = accounts::MigratePool {
+        vault_token_account: other.vault_token_account,
+        ..launchpad.migrate_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.migrate(accounts).await,
+        anchor_error(AmmError::InvalidMint)
+    );
+
+    // Right mint, but not the minting vault's token account
+    let decoy = ata(&launchpad.user.pubkey(), &meme.mint);
+    let accounts = accounts::MigratePool {
+        vault_token_account: decoy,
+        ..launchpad.migrate_accounts(&meme)
+    };
+    assert_eq!(
+        launchpad.migrate(accounts).await,
+        anchor_error(AmmError::InvalidVault)
+    );
+
+    // Nothing above moved the pool's funds
+    let accounts = launchpad.migrate_accounts(&meme);
+    launchpad.migrate(accounts).await.unwrap();
+    let cp_pool: CpPool = launchpad.anchor_account(&meme.cp_pool).await;
+    assert_eq!(cp_pool.token_mint, meme.mint);
+    assert_eq!(
+        launchpad.token_balance(&meme.cp_token_vault).await,
+        cp_pool.token_reserve
+    );
+}
+
+#[tokio::test]
+async fn cp_swaps_accept_the_pool_accounts() {
+    let (mut launchpad, meme, _) = two_cp_pools().await;
+    let user_token_account = ata(&launchpad.user.pubkey(), &meme.mint);
+    let before = launchpad.token_balance(&user_token_account).await;
+
+    let accounts = launchpad.cp_swap_accounts(&meme);
+    launchpad
+        .cp_swap(accounts, LAMPORTS_PER_SOL, true)
+        .await
+        .unwrap();
+    let bought = launchpad.token_balance(&user_token_account).await - before;
+    assert!(bought > 0);
+
+    let accounts = launchpad.cp_swap_accounts(&meme);
+    launchpad.cp_swap(accounts, bought, false).await.unwrap();
+    assert_eq!(launchpad.token_balance(&user_token_account).await, before);
+}
+
+#[tokio::test]
+async fn cp_swap_rejects_foreign_accounts() {
+    let (mut launchpad, meme, other) = two_cp_pools().await;
+
+    for is_buy in [true, false] {
+        let amount = LAMPORTS_PER_SOL + is_buy as u64;
+
+        let accounts = accounts::CpSwap {
+            token_mint: other.mint,
+            ..launchpad.cp_swap_accounts(&meme)
+        };
+        assert_eq!(
+            launchpad.cp_swap(accounts, amount, is_buy).await,
+            anchor_error(AmmError::InvalidMint)
+        );
+
+        // The curve pool carrying the halt flag is a PDA of the cp pool's mint
+        let accounts = accounts::CpSwap {
+            pool: other.pool,
+            ..launchpad.cp_swap_accounts(&meme)
+        };
+        assert_eq!(
+            launchpad.cp_swap(accounts, amount, is_buy).await,
+            anchor_error(AnchorErrorCode::ConstraintSeeds as u32)
+        );
+
+        let accounts = accounts::CpSwap {
+            sol_vault: other.cp_sol_vault,
+            ..launchpad.cp_swap_accounts(&meme)
+        };
+        assert_eq!(
+            launchpad.cp_swap(accounts, amount, is_buy).await,
+            anchor_error(AmmError::InvalidVault)
+        );
+
+        let accounts = accounts::CpSwap {
+            token_vault: other.cp_token_vault,
+            ..launchpad.cp_swap_accounts(&meme)
+        };
+        assert_eq!(
+            launchpad.cp_swap(accounts, amount, is_buy).await,
+            anchor_error(AmmError::InvalidVault)
+        );
+
+        let accounts = accounts::CpSwap {
+            user_token_account: ata(&launchpad.user.pubkey(), &other.mint),
+            ..launchpad.cp_swap_accounts(&meme)
+        };
+        assert_eq!(
+            launchpad.cp_swap(accounts, amount, is_buy).await,
+            anchor_error(AmmError::InvalidMint)
+        );
+    }
+}
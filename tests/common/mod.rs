@@ -0,0 +1,542 @@
+// Shared harness for the instruction tests: the program runs natively
+// under solana-program-test, with the bundled System, Token-2022 and
+// Associated Token programs.
+
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{
+    self, get_associated_token_address_with_program_id, spl_associated_token_account,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::StateWithExtensions,
+    state::{Account as SplTokenAccount, Mint as SplMint},
+};
+use meme_launchpad::{
+    AmmPool, LaunchConfigParams, ProtocolState, AMM_POOL_SEED, CP_POOL_SEED, CP_SOL_VAULT_SEED,
+    CP_TOKEN_VAULT_SEED, CREATOR_FEE_VAULT_SEED, DEFAULT_CREATOR_FEE_BPS,
+    DEFAULT_GRADUATION_SOL_THRESHOLD, DEFAULT_LAUNCH_WINDOW_SLOTS,
+    DEFAULT_MAX_BUY_PER_WALLET_LAMPORTS, DEFAULT_TRADE_FEE_BPS, LAUNCH_BUY_SEED,
+    LAUNCH_CONFIG_SEED, LP_LOCK_SEED, LP_MINT_SEED, MINT_FEE_LAMPORTS, POOL_SOL_VAULT_SEED,
+    POOL_TOKEN_VAULT_SEED,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+pub type TxResult = std::result::Result<(), TransactionError>;
+
+// Anchor's entrypoint wants the account slice to live as long as the
+// accounts themselves, which the builtin processor signature can't promise
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    meme_launchpad::entry(program_id, accounts, data)
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &meme_launchpad::ID).0
+}
+
+pub fn ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::ID)
+}
+
+// The failure a program error code produces in the first instruction
+pub fn anchor_error(error: impl Into<u32>) -> TxResult {
+    Err(TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(error.into()),
+    ))
+}
+
+// Every PDA belonging to one meme token, its curve pool and its
+// constant-product pool
+pub struct MemeKeys {
+    pub meme_id: [u8; 32],
+    pub meme_token_state: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub pool: Pubkey,
+    pub sol_vault: Pubkey,
+    pub token_vault: Pubkey,
+    pub creator_fee_vault: Pubkey,
+    pub cp_pool: Pubkey,
+    pub cp_sol_vault: Pubkey,
+    pub cp_token_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_lock_account: Pubkey,
+}
+
+impl MemeKeys {
+    pub fn new(tag: u8) -> Self {
+        let meme_id = [tag; 32];
+        let mint = pda(&[b"meme_mint", &meme_id]);
+        let vault = pda(&[b"vault", mint.as_ref()]);
+        Self {
+            meme_id,
+            meme_token_state: pda(&[b"meme_token_state", &meme_id]),
+            mint,
+            vault,
+            vault_token_account: ata(&vault, &mint),
+            pool: pda(&[AMM_POOL_SEED, mint.as_ref()]),
+            sol_vault: pda(&[POOL_SOL_VAULT_SEED, mint.as_ref()]),
+            token_vault: pda(&[POOL_TOKEN_VAULT_SEED, mint.as_ref()]),
+            creator_fee_vault: pda(&[CREATOR_FEE_VAULT_SEED, mint.as_ref()]),
+            cp_pool: pda(&[CP_POOL_SEED, mint.as_ref()]),
+            cp_sol_vault: pda(&[CP_SOL_VAULT_SEED, mint.as_ref()]),
+            cp_token_vault: pda(&[CP_TOKEN_VAULT_SEED, mint.as_ref()]),
+            lp_mint: pda(&[LP_MINT_SEED, mint.as_ref()]),
+            lp_lock_account: pda(&[LP_LOCK_SEED, mint.as_ref()]),
+        }
+    }
+}
+
+// Protocol state and the default launch config, plus a funded wallet
+pub struct Launchpad {
+    pub context: ProgramTestContext,
+    pub authority: Keypair,
+    pub user: Keypair,
+    pub protocol_state: Pubkey,
+    pub fee_vault: Pubkey,
+    pub launch_config: Pubkey,
+}
+
+impl Launchpad {
+    pub async fn new() -> Self {
+        let mut program_test = ProgramTest::new(
+            "meme_launchpad",
+            meme_launchpad::ID,
+            processor!(process_instruction),
+        );
+
+        let authority = Keypair::new();
+        let user = Keypair::new();
+        for (wallet, lamports) in [(&authority, 10), (&user, 1_000)] {
+            let account = Account::new(lamports * LAMPORTS_PER_SOL, 0, &system_program::ID);
+            program_test.add_account(wallet.pubkey(), account);
+        }
+
+        // Written directly: initialize_protocol_state needs the
+        // upgradeable loader's ProgramData account
+        let (protocol_state, bump) =
+            Pubkey::find_program_address(&[b"protocol_state_v2"], &meme_launchpad::ID);
+        let state = ProtocolState {
+            authority: authority.pubkey(),
+            fee_lamports: MINT_FEE_LAMPORTS,
+            bump,
+            pending_authority: Pubkey::default(),
+            trade_fee_bps: DEFAULT_TRADE_FEE_BPS,
+            creator_fee_bps: DEFAULT_CREATOR_FEE_BPS,
+            graduation_sol_threshold: DEFAULT_GRADUATION_SOL_THRESHOLD,
+            launch_window_slots: DEFAULT_LAUNCH_WINDOW_SLOTS,
+            max_buy_per_wallet_lamports: DEFAULT_MAX_BUY_PER_WALLET_LAMPORTS,
+            paused: 0,
+        };
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        data.resize(128, 0);
+        let account = Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: meme_launchpad::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        program_test.add_account(protocol_state, account);
+
+        let mut launchpad = Self {
+            context: program_test.start_with_context().await,
+            authority,
+            user,
+            protocol_state,
+            fee_vault: pda(&[b"fee_vault"]),
+            launch_config: pda(&[LAUNCH_CONFIG_SEED, &0u16.to_le_bytes()]),
+        };
+
+        launchpad
+            .create_launch_config(0, default_launch_config_params())
+            .await
+            .unwrap();
+
+        launchpad
+    }
+
+    pub async fn create_launch_config(
+        &mut self,
+        config_id: u16,
+        params: LaunchConfigParams,
+    ) -> TxResult {
+        let accounts = meme_launchpad::accounts::CreateLaunchConfig {
+            authority: self.authority.pubkey(),
+            protocol_state: self.protocol_state,
+            launch_config: pda(&[LAUNCH_CONFIG_SEED, &config_id.to_le_bytes()]),
+            system_program: system_program::ID,
+        };
+        let args = meme_launchpad::instruction::CreateLaunchConfig { config_id, params };
+        let authority = self.authority.insecure_clone();
+        self.send(&[program_instruction(accounts, args)], &[&authority])
+            .await
+    }
+
+    pub async fn set_pool_halted(&mut self, meme: &MemeKeys, halted: bool) {
+        let accounts = meme_launchpad::accounts::SetPoolHalted {
+            authority: self.authority.pubkey(),
+            protocol_state: self.protocol_state,
+            pool: meme.pool,
+        };
+        let args = meme_launchpad::instruction::SetPoolHalted { halted, reason: 0 };
+        let authority = self.authority.insecure_clone();
+        self.send(&[program_instruction(accounts, args)], &[&authority])
+            .await
+            .unwrap();
+    }
+
+    // Sends one transaction paid for by the user, who always signs
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TxResult {
+        let blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let mut all_signers = vec![&self.user];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.user.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .map_err(|error: BanksClientError| error.unwrap())
+    }
+
+    pub async fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+    ) -> TxResult {
+        self.send(&[program_instruction(accounts, args)], &[]).await
+    }
+
+    // Moves the clock past the launch window of every pool created so far
+    pub async fn end_launch_window(&mut self) {
+        let slot = self.context.banks_client.get_root_slot().await.unwrap();
+        self.context
+            .warp_to_slot(slot + DEFAULT_LAUNCH_WINDOW_SLOTS + 1)
+            .unwrap();
+    }
+
+    pub async fn account_data(&mut self, key: &Pubkey) -> Vec<u8> {
+        self.context
+            .banks_client
+            .get_account(*key)
+            .await
+            .unwrap()
+            .expect("account does not exist")
+            .data
+    }
+
+    pub async fn anchor_account<T: AccountDeserialize>(&mut self, key: &Pubkey) -> T {
+        T::try_deserialize(&mut &self.account_data(key).await[..]).unwrap()
+    }
+
+    pub async fn token_balance(&mut self, key: &Pubkey) -> u64 {
+        let data = self.account_data(key).await;
+        StateWithExtensions::<SplTokenAccount>::unpack(&data)
+            .unwrap()
+            .base
+            .amount
+    }
+
+    pub async fn mint_state(&mut self, key: &Pubkey) -> SplMint {
+        let data = self.account_data(key).await;
+        StateWithExtensions::<SplMint>::unpack(&data).unwrap().base
+    }
+
+    pub async fn mint_meme(&mut self, tag: u8) -> MemeKeys {
+        let meme = MemeKeys::new(tag);
+        let accounts = meme_launchpad::accounts::MintMemeToken {
+            minter: self.user.pubkey(),
+            meme_token_state: meme.meme_token_state,
+            mint: meme.mint,
+            vault: meme.vault,
+            vault_token_account: meme.vault_token_account,
+            fee_vault: self.fee_vault,
+            protocol_state: self.protocol_state,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+        };
+        let args = meme_launchpad::instruction::MintMemeToken {
+            meme_id: meme.meme_id,
+            name: "Test Meme".to_string(),
+            symbol: "TMEME".to_string(),
+            uri: "https://example.com/meme.json".to_string(),
+        };
+        self.process(accounts, args).await.unwrap();
+        meme
+    }
+
+    pub async fn launch_meme(&mut self, tag: u8) -> MemeKeys {
+        let meme = self.mint_meme(tag).await;
+        let accounts = self.initialize_pool_accounts(&meme);
+        self.initialize_pool(accounts).await.unwrap();
+        meme
+    }
+
+    // A second wallet, funded by the user
+    pub async fn funded_wallet(&mut self, lamports: u64) -> Keypair {
+        let wallet = Keypair::new();
+        let transfer =
+            system_instruction::transfer(&self.user.pubkey(), &wallet.pubkey(), lamports);
+        self.send(&[transfer], &[]).await.unwrap();
+        wallet
+    }
+
+    pub async fn create_token_account(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let instruction =
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &self.user.pubkey(),
+                owner,
+                mint,
+                &spl_token_2022::ID,
+            );
+        self.send(&[instruction], &[]).await.unwrap();
+        ata(owner, mint)
+    }
+
+    pub fn initialize_pool_accounts(
+        &self,
+        meme: &MemeKeys,
+    ) -> meme_launchpad::accounts::InitializePool {
+        meme_launchpad::accounts::InitializePool {
+            initializer: self.user.pubkey(),
+            token_mint: meme.mint,
+            meme_token_state: meme.meme_token_state,
+            pool: meme.pool,
+            sol_vault: meme.sol_vault,
+            token_vault: meme.token_vault,
+            creator_fee_vault: meme.creator_fee_vault,
+            protocol_state: self.protocol_state,
+            launch_config: self.launch_config,
+            vault: meme.vault,
+            vault_token_account: meme.vault_token_account,
+            fee_vault: self.fee_vault,
+            initializer_token_account: ata(&self.user.pubkey(), &meme.mint),
+            token_program: spl_token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn initialize_pool(
+        &mut self,
+        accounts: meme_launchpad::accounts::InitializePool,
+    ) -> TxResult {
+        let args = meme_launchpad::instruction::InitializeAmmPool {
+            dev_buy_sol: 0,
+            min_dev_buy_tokens: 0,
+        };
+        self.process(accounts, args).await
+    }
+
+    pub fn buy_accounts(&self, meme: &MemeKeys) -> meme_launchpad::accounts::SwapSolForTokens {
+        let user = self.user.pubkey();
+        meme_launchpad::accounts::SwapSolForTokens {
+            user,
+            pool: meme.pool,
+            token_mint: meme.mint,
+            sol_vault: meme.sol_vault,
+            fee_vault: self.fee_vault,
+            protocol_state: self.protocol_state,
+            creator_fee_vault: meme.creator_fee_vault,
+            token_vault: meme.token_vault,
+            user_token_account: ata(&user, &meme.mint),
+            launch_buys: Some(pda(&[LAUNCH_BUY_SEED, meme.pool.as_ref(), user.as_ref()])),
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn buy(
+        &mut self,
+        accounts: meme_launchpad::accounts::SwapSolForTokens,
+        sol_amount: u64,
+    ) -> TxResult {
+        let args = meme_launchpad::instruction::SwapSolToTokens {
+            sol_amount,
+            min_token_amount: 0,
+        };
+        self.process(accounts, args).await
+    }
+
+    pub fn sell_accounts(&self, meme: &MemeKeys) -> meme_launchpad::accounts::SwapTokensForSol {
+        meme_launchpad::accounts::SwapTokensForSol {
+            user: self.user.pubkey(),
+            pool: meme.pool,
+            token_mint: meme.mint,
+            sol_vault: meme.sol_vault,
+            fee_vault: self.fee_vault,
+            protocol_state: self.protocol_state,
+            creator_fee_vault: meme.creator_fee_vault,
+            token_vault: meme.token_vault,
+            user_token_account: ata(&self.user.pubkey(), &meme.mint),
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn sell(
+        &mut self,
+        accounts: meme_launchpad::accounts::SwapTokensForSol,
+        token_amount: u64,
+    ) -> TxResult {
+        let args = meme_launchpad::instruction::SwapTokensToSol {
+            token_amount,
+            min_sol_amount: 0,
+        };
+        self.process(accounts, args).await
+    }
+
+    // Buys out the rest of the curve once the launch window has ended
+    pub async fn graduate(&mut self, meme: &MemeKeys) {
+        let accounts = meme_launchpad::accounts::SwapSolForTokens {
+            launch_buys: None,
+            ..self.buy_accounts(meme)
+        };
+        self.buy(accounts, 2 * DEFAULT_GRADUATION_SOL_THRESHOLD)
+            .await
+            .unwrap();
+        let pool: AmmPool = self.anchor_account(&meme.pool).await;
+        assert!(pool.is_complete);
+    }
+
+    pub fn migrate_accounts(&self, meme: &MemeKeys) -> meme_launchpad::accounts::MigratePool {
+        meme_launchpad::accounts::MigratePool {
+            payer: self.user.pubkey(),
+            protocol_state: self.protocol_state,
+            token_mint: meme.mint,
+            pool: meme.pool,
+            sol_vault: meme.sol_vault,
+            token_vault: meme.token_vault,
+            vault: meme.vault,
+            vault_token_account: meme.vault_token_account,
+            cp_pool: meme.cp_pool,
+            cp_sol_vault: meme.cp_sol_vault,
+            cp_token_vault: meme.cp_token_vault,
+            lp_mint: meme.lp_mint,
+            lp_lock_account: meme.lp_lock_account,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn migrate(&mut self, accounts: meme_launchpad::accounts::MigratePool) -> TxResult {
+        self.process(accounts, meme_launchpad::instruction::MigratePool {})
+            .await
+    }
+
+    pub fn cp_swap_accounts(&self, meme: &MemeKeys) -> meme_launchpad::accounts::CpSwap {
+        meme_launchpad::accounts::CpSwap {
+            user: self.user.pubkey(),
+            protocol_state: self.protocol_state,
+            cp_pool: meme.cp_pool,
+            pool: meme.pool,
+            token_mint: meme.mint,
+            sol_vault: meme.cp_sol_vault,
+            token_vault: meme.cp_token_vault,
+            user_token_account: ata(&self.user.pubkey(), &meme.mint),
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn cp_swap(
+        &mut self,
+        accounts: meme_launchpad::accounts::CpSwap,
+        amount_in: u64,
+        is_buy: bool,
+    ) -> TxResult {
+        let args = meme_launchpad::instruction::CpSwap {
+            amount_in,
+            min_amount_out: 0,
+            is_buy,
+        };
+        self.process(accounts, args).await
+    }
+}
+
+// Every field at the protocol default
+pub fn default_launch_config_params() -> LaunchConfigParams {
+    LaunchConfigParams {
+        initial_sol: None,
+        curve_tokens: None,
+        virtual_sol_reserve: None,
+        virtual_token_reserve: None,
+        graduation_sol_threshold: None,
+        trade_fee_bps: None,
+        creator_fee_bps: None,
+        reserve_liquidity_bps: None,
+        reserve_creator_bps: None,
+        reserve_burn_bps: None,
+        vesting_cliff_seconds: None,
+        vesting_duration_seconds: None,
+        curve_type: None,
+    }
+}
+
+pub fn program_instruction(
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        meme_launchpad::ID,
+        &args.data(),
+        accounts.to_account_metas(None),
+    )
+}
+
+// Two live pools: `meme` is traded, `other` supplies the foreign
+// accounts. Pool creation also opened the user's token account for each.
+pub async fn two_pools() -> (Launchpad, MemeKeys, MemeKeys) {
+    let mut launchpad = Launchpad::new().await;
+    let meme = launchpad.launch_meme(1).await;
+    let other = launchpad.launch_meme(2).await;
+    (launchpad, meme, other)
+}
+
+// Two pools that have graduated off their curves, not yet migrated
+pub async fn two_graduated_pools() -> (Launchpad, MemeKeys, MemeKeys) {
+    let (mut launchpad, meme, other) = two_pools().await;
+    launchpad.end_launch_window().await;
+    launchpad.graduate(&meme).await;
+    launchpad.graduate(&other).await;
+    (launchpad, meme, other)
+}
+
+// Two migrated constant-product pools
+pub async fn two_cp_pools() -> (Launchpad, MemeKeys, MemeKeys) {
+    let (mut launchpad, meme, other) = two_graduated_pools().await;
+    for keys in [&meme, &other] {
+        let accounts = launchpad.migrate_accounts(keys);
+        launchpad.migrate(accounts).await.unwrap();
+    }
+    (launchpad, meme, other)
+}
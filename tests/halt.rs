@@ -0,0 +1,33 @@
+mod common;
+
+use common::*;
+use meme_launchpad::AmmError;
+use solana_program_test::tokio;
+
+#[tokio::test]
+async fn halted_pools_stop_cp_swaps() {
+    let (mut launchpad, meme, other) = two_cp_pools().await;
+    launchpad.set_pool_halted(&meme, true).await;
+
+    for is_buy in [true, false] {
+        let accounts = launchpad.cp_swap_accounts(&meme);
+        assert_eq!(
+            launchpad.cp_swap(accounts, LAMPORTS_PER_SOL, is_buy).await,
+            anchor_error(AmmError::PoolHalted)
+        );
+    }
+
+    // Other pools keep trading
+    let accounts = launchpad.cp_swap_accounts(&other);
+    launchpad
+        .cp_swap(accounts, LAMPORTS_PER_SOL, true)
+        .await
+        .unwrap();
+
+    launchpad.set_pool_halted(&meme, false).await;
+    let accounts = launchpad.cp_swap_accounts(&meme);
+    launchpad
+        .cp_swap(accounts, LAMPORTS_PER_SOL, true)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::*;
+use meme_launchpad::{accounts, instruction, AmmPool, ErrorCode};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn only_the_minter_can_create_the_pool() {
+    let mut launchpad = Launchpad::new().await;
+    let meme = launchpad.mint_meme(1).await;
+
+    // A sniper opening the pool with an uncapped dev buy
+    let sniper = launchpad.funded_wallet(10 * LAMPORTS_PER_SOL).await;
+    let accounts = accounts::InitializePool {
+        initializer: sniper.pubkey(),
+        initializer_token_account: ata(&sniper.pubkey(), &meme.mint),
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    let args = instruction::InitializeAmmPool {
+        dev_buy_sol: 5 * LAMPORTS_PER_SOL,
+        min_dev_buy_tokens: 0,
+    };
+    let initialize = program_instruction(accounts, args);
+    assert_eq!(
+        launchpad.send(&[initialize], &[&sniper]).await,
+        anchor_error(ErrorCode::Unauthorized)
+    );
+
+    let accounts = launchpad.initialize_pool_accounts(&meme);
+    launchpad.initialize_pool(accounts).await.unwrap();
+    let pool: AmmPool = launchpad.anchor_account(&meme.pool).await;
+    assert_eq!(pool.token_mint, meme.mint);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::*;
+use meme_launchpad::{accounts, AmmPool, CurveType, ErrorCode, LaunchConfigParams};
+use solana_program_test::tokio;
+
+// Flat at 2e-7 SOL per token: the 800M curve tokens cost 160 SOL
+const PRICED_LINEAR: CurveType = CurveType::Linear {
+    start_price: 200_000_000_000,
+    slope: 1,
+};
+
+#[tokio::test]
+async fn curves_too_cheap_to_graduate_are_rejected() {
+    let mut launchpad = Launchpad::new().await;
+
+    // The whole curve supply for about a lamport
+    let params = LaunchConfigParams {
+        curve_type: Some(CurveType::Linear {
+            start_price: 1,
+            slope: 1,
+        }),
+        ..default_launch_config_params()
+    };
+    assert_eq!(
+        launchpad.create_launch_config(1, params).await,
+        anchor_error(ErrorCode::InvalidLaunchConfig)
+    );
+
+    let params = LaunchConfigParams {
+        curve_type: Some(PRICED_LINEAR),
+        ..default_launch_config_params()
+    };
+    launchpad.create_launch_config(1, params).await.unwrap();
+}
+
+#[tokio::test]
+async fn pools_take_the_curve_from_their_config() {
+    let mut launchpad = Launchpad::new().await;
+    let params = LaunchConfigParams {
+        curve_type: Some(PRICED_LINEAR),
+        ..default_launch_config_params()
+    };
+    launchpad.create_launch_config(1, params).await.unwrap();
+
+    let meme = launchpad.mint_meme(1).await;
+    let accounts = accounts::InitializePool {
+        launch_config: pda(&[meme_launchpad::LAUNCH_CONFIG_SEED, &1u16.to_le_bytes()]),
+        ..launchpad.initialize_pool_accounts(&meme)
+    };
+    launchpad.initialize_pool(accounts).await.unwrap();
+
+    let pool: AmmPool = launchpad.anchor_account(&meme.pool).await;
+    assert_eq!(pool.curve_type, PRICED_LINEAR);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::{self, error::TokenError};
+use common::*;
+use meme_launchpad::TOTAL_SUPPLY;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::transaction::TransactionError;
+
+#[tokio::test]
+async fn minted_supply_is_fixed() {
+    let mut launchpad = Launchpad::new().await;
+    let meme = launchpad.mint_meme(1).await;
+
+    let mint = launchpad.mint_state(&meme.mint).await;
+    assert_eq!(mint.mint_authority, COption::None);
+    assert_eq!(mint.freeze_authority, COption::None);
+    assert_eq!(mint.supply, TOTAL_SUPPLY);
+    assert_eq!(
+        launchpad.token_balance(&meme.vault_token_account).await,
+        TOTAL_SUPPLY
+    );
+
+    // Not even the former authority can mint more. The vault PDA can't sign
+    // a transaction, and with no mint authority left the signature is moot.
+    let mut mint_to = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::ID,
+        &meme.mint,
+        &meme.vault_token_account,
+        &meme.vault,
+        &[],
+        1,
+    )
+    .unwrap();
+    mint_to.accounts[2].is_signer = false;
+    assert_eq!(
+        launchpad.send(&[mint_to], &[]).await,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::FixedSupply as u32)
+        ))
+    );
+    assert_eq!(launchpad.mint_state(&meme.mint).await.supply, TOTAL_SUPPLY);
+}